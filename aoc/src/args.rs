@@ -0,0 +1,94 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct UsageError(pub String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Command line arguments split into positionals and `--name value` options.
+///
+/// An option directly followed by another option (or by nothing) is a flag.
+#[derive(Debug, Default)]
+pub struct Args {
+    positionals: Vec<String>,
+    options: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Self {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                parsed.positionals.push(arg);
+                continue;
+            };
+            if let Some((name, value)) = name.split_once('=') {
+                parsed
+                    .options
+                    .insert(name.to_string(), Some(value.to_string()));
+                continue;
+            }
+            let value = args.next_if(|next| !next.starts_with("--"));
+            parsed.options.insert(name.to_string(), value);
+        }
+        parsed
+    }
+
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positionals.get(index).map(String::as_str)
+    }
+
+    /// True if `--name` was given at all, with or without a value.
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options.get(name)?.as_deref()
+    }
+
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> Result<Option<T>, UsageError> {
+        match self.options.get(name) {
+            None => Ok(None),
+            Some(None) => Err(UsageError(format!("--{} requires a value", name))),
+            Some(Some(raw)) => raw
+                .parse()
+                .map(Some)
+                .map_err(|_| UsageError(format!("invalid value for --{}: {}", name, raw))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(raw: &str) -> Args {
+        Args::parse(raw.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = args("run --day 9 --part=2 --verbose");
+        assert_eq!(parsed.positional(0), Some("run"));
+        assert_eq!(parsed.positional(1), None);
+        assert_eq!(parsed.parse_value::<u8>("day"), Ok(Some(9)));
+        assert_eq!(parsed.value("part"), Some("2"));
+        assert_eq!(parsed.value("verbose"), None);
+        assert!(parsed.flag("verbose"));
+        assert!(!parsed.flag("quiet"));
+        assert_eq!(parsed.parse_value::<u8>("missing"), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_value_errors() {
+        let parsed = args("run --day nine --part");
+        assert!(parsed.parse_value::<u8>("day").is_err());
+        assert!(parsed.parse_value::<u8>("part").is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use day_10::{Board, ImageFormat, Style};
+
+use std::fs;
+
+use crate::{args::Args, args::UsageError, error::RunError, load};
+
+const DAY: u8 = 10;
+
+pub fn run(args: &Args) -> Result<(), RunError> {
+    match args.positional(1) {
+        Some("show") => show(args),
+        Some("image") => image(args),
+        Some("distances") => distances(args),
+        Some(command) => Err(UsageError(format!("unknown day10 command: {}", command)).into()),
+        None => Err(UsageError("missing day10 command".into()).into()),
+    }
+}
+
+fn show(args: &Args) -> Result<(), RunError> {
+    let style: Style = args.parse_value("style")?.unwrap_or(Style::Plain);
+    let (board, path, input) = load(args, DAY, None, str::parse::<Board>)?;
+    match day_10::render_board(&board, style) {
+        Ok(map) => print!("{}", map),
+        Err(error) => return Err(RunError::solve(DAY, None, path, input, error)),
+    }
+    Ok(())
+}
+
+fn image(args: &Args) -> Result<(), RunError> {
+    let format: ImageFormat = args.parse_value("format")?.unwrap_or(ImageFormat::Svg);
+    let scale: usize = args.parse_value("scale")?.unwrap_or(8);
+    if scale == 0 {
+        return Err(UsageError("--scale must be at least 1".into()).into());
+    }
+    let Some(output) = args.value("output") else {
+        return Err(UsageError("--output is required".into()).into());
+    };
+    let (board, path, input) = load(args, DAY, None, str::parse::<Board>)?;
+    let image = match day_10::render_image(&board, format, scale) {
+        Ok(image) => image,
+        Err(error) => return Err(RunError::solve(DAY, None, path, input, error)),
+    };
+    fs::write(output, image).map_err(|source| RunError::Write {
+        path: output.to_string(),
+        source,
+    })
+}
+
+fn distances(args: &Args) -> Result<(), RunError> {
+    let style: Style = args.parse_value("style")?.unwrap_or(Style::Plain);
+    let (board, path, input) = load(args, DAY, None, str::parse::<Board>)?;
+    let distances = match board.distances() {
+        Ok(distances) => distances,
+        Err(error) => return Err(RunError::solve(DAY, None, path, input, error)),
+    };
+    print!("{}", distances.render(style));
+    let (x, y) = distances.farthest();
+    println!(
+        "farthest: ({}, {}) at distance {}",
+        x,
+        y,
+        distances.max_distance()
+    );
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use aoc_common::Part;
+use day_8::{Format, Network, NodePattern, TraceFormat, Walker};
+
+use crate::{args::Args, args::UsageError, error::RunError, load};
+
+const DAY: u8 = 8;
+
+pub fn run(args: &Args) -> Result<(), RunError> {
+    match args.positional(1) {
+        Some("walk") => walk(args),
+        Some("export") => export(args),
+        Some("trace") => trace(args),
+        Some("analyze") => analyze(args),
+        Some(command) => Err(UsageError(format!("unknown day8 command: {}", command)).into()),
+        None => Err(UsageError("missing day8 command".into()).into()),
+    }
+}
+
+/// The walk for `--part`, with `--start` and `--goal` overriding its node patterns.
+fn walker<'a>(args: &Args, network: &'a Network, part: Part) -> Result<Walker<'a>, RunError> {
+    let (start, goal) = NodePattern::for_part(part);
+    let start = args.parse_value("start")?.unwrap_or(start);
+    let goal = args.parse_value("goal")?.unwrap_or(goal);
+    let max_steps = args.parse_value("max-steps")?;
+    Ok(Walker::new(network, start, goal).max_steps(max_steps))
+}
+
+fn walk(args: &Args) -> Result<(), RunError> {
+    let part: Part = args.parse_value("part")?.unwrap_or(Part::One);
+    let (network, path, input) = load(args, DAY, Some(part), day_8::parse_input)?;
+    match walker(args, &network, part)?.steps() {
+        Ok(steps) => {
+            println!("day {} part {}: {}", DAY, part, steps);
+            Ok(())
+        }
+        Err(error) => Err(RunError::solve(DAY, Some(part), path, input, error)),
+    }
+}
+
+fn export(args: &Args) -> Result<(), RunError> {
+    let format: Format = args.parse_value("format")?.unwrap_or(Format::Dot);
+    let (network, path, input) = load(args, DAY, None, day_8::parse_input)?;
+    let from = match args.value("from") {
+        Some(name) => match network.graph.id(name) {
+            Some(id) => Some(id),
+            None => {
+                let error = day_8::Error::MissingStart(name.to_string());
+                return Err(RunError::solve(DAY, None, path, input, error));
+            }
+        },
+        None => None,
+    };
+    print!("{}", day_8::export(&network.graph, format, from));
+    Ok(())
+}
+
+fn trace(args: &Args) -> Result<(), RunError> {
+    let part: Part = args.parse_value("part")?.unwrap_or(Part::One);
+    let format: TraceFormat = args
+        .parse_value("format")?
+        .unwrap_or(TraceFormat::JsonLines);
+    let (network, path, input) = load(args, DAY, Some(part), day_8::parse_input)?;
+    match day_8::trace(&walker(args, &network, part)?, args.flag("goals-only")) {
+        Ok(steps) => {
+            print!("{}", day_8::render_trace(&steps, format));
+            Ok(())
+        }
+        Err(error) => Err(RunError::solve(DAY, Some(part), path, input, error)),
+    }
+}
+
+fn analyze(args: &Args) -> Result<(), RunError> {
+    let (network, path, input) = load(args, DAY, Some(Part::Two), day_8::parse_input)?;
+    match day_8::analyze(&network) {
+        Ok(analysis) => {
+            println!("{}", analysis);
+            Ok(())
+        }
+        Err(error) => Err(RunError::solve(DAY, Some(Part::Two), path, input, error)),
+    }
+}
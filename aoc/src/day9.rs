@@ -0,0 +1,111 @@
+use day_9::{format_polynomial, Integer, Style, Value};
+
+use std::{
+    fs::File,
+    io::{self, BufReader},
+};
+
+use crate::{args::Args, args::UsageError, error::RunError, input_path, load};
+
+const DAY: u8 = 9;
+
+pub fn run(args: &Args) -> Result<(), RunError> {
+    match args.positional(1) {
+        Some("extrapolate") => extrapolate(args),
+        Some("stream") => stream(args),
+        Some("show") => show(args),
+        Some(command) => Err(UsageError(format!("unknown day9 command: {}", command)).into()),
+        None => Err(UsageError("missing day9 command".into()).into()),
+    }
+}
+
+fn extrapolate(args: &Args) -> Result<(), RunError> {
+    let steps: isize = args.parse_value("steps")?.unwrap_or(1);
+    let (histories, path, input) = load(args, DAY, None, day_9::parse_histories)?;
+    let mut total = Integer::from_isize(0);
+    for (index, history) in histories.iter().enumerate() {
+        let line = index + 1;
+        let table = match day_9::difference_table(history, line) {
+            Ok(table) => table,
+            Err(error) => return Err(RunError::solve(DAY, None, path, input, error)),
+        };
+        let value = if steps >= 0 {
+            table.forward(steps as usize)
+        } else {
+            table.backward(steps.unsigned_abs())
+        };
+        let Some(value) = value else {
+            let error = day_9::Error::Overflow { line };
+            return Err(RunError::solve(DAY, None, path, input, error));
+        };
+        total = match Value::checked_add(&total, &value) {
+            Some(total) => total,
+            None => {
+                let error = day_9::Error::Overflow { line };
+                return Err(RunError::solve(DAY, None, path, input, error));
+            }
+        };
+        if !args.flag("coefficients") {
+            println!("line {}: {}", line, value);
+            continue;
+        }
+        match table.coefficients() {
+            Some(coefficients) => println!(
+                "line {}: {}  p(x) = {}",
+                line,
+                value,
+                format_polynomial(&coefficients)
+            ),
+            None => println!(
+                "line {}: {}  p(x) does not fit in 128-bit fractions",
+                line, value
+            ),
+        }
+    }
+    println!("sum: {}", total);
+    Ok(())
+}
+
+fn show(args: &Args) -> Result<(), RunError> {
+    let style: Style = args.parse_value("style")?.unwrap_or(Style::Plain);
+    let selected: Option<usize> = args.parse_value("line")?;
+    let (histories, path, input) = load(args, DAY, None, day_9::parse_histories)?;
+    if selected.is_some_and(|line| line == 0 || line > histories.len()) {
+        return Err(UsageError(format!("--line must be between 1 and {}", histories.len())).into());
+    }
+    for (index, history) in histories.iter().enumerate() {
+        let line = index + 1;
+        if selected.is_some_and(|selected| selected != line) {
+            continue;
+        }
+        match day_9::render_pyramid(history, line, style) {
+            Ok(pyramid) => println!("line {}:\n{}", line, pyramid),
+            Err(error) => return Err(RunError::solve(DAY, None, path, input, error)),
+        }
+    }
+    Ok(())
+}
+
+/// Solves both parts in one pass over `--input`, which may be `-` for stdin,
+/// without reading the whole file into memory.
+fn stream(args: &Args) -> Result<(), RunError> {
+    let path = input_path(args, DAY);
+    let result = if path == "-" {
+        day_9::solve_stream(io::stdin().lock())
+    } else {
+        let file = File::open(&path).map_err(|source| RunError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        day_9::solve_stream(BufReader::new(file))
+    };
+    match result {
+        Ok(predictions) => {
+            println!("day {} part 1: {}", DAY, predictions.next);
+            println!("day {} part 2: {}", DAY, predictions.previous);
+            Ok(())
+        }
+        // The input was never held in memory, so diagnostics point at the path only.
+        Err(error) => Err(RunError::solve(DAY, None, path, String::new(), error)),
+    }
+}
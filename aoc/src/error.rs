@@ -0,0 +1,181 @@
+use std::{error::Error, fmt, io};
+
+use aoc_common::{ParseError, Part};
+
+use crate::args::UsageError;
+
+#[derive(Debug)]
+pub enum RunError {
+    Usage(UsageError),
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Write {
+        path: String,
+        source: io::Error,
+    },
+    Solve {
+        day: u8,
+        part: Option<Part>,
+        path: String,
+        input: String,
+        source: Box<dyn Error>,
+    },
+}
+
+impl RunError {
+    pub fn solve(
+        day: u8,
+        part: Option<Part>,
+        path: String,
+        input: String,
+        source: impl Into<Box<dyn Error>>,
+    ) -> Self {
+        RunError::Solve {
+            day,
+            part,
+            path,
+            input,
+            source: source.into(),
+        }
+    }
+
+    /// Process exit status: 1 for bad puzzle input, 2 for bad usage, 3 for files that
+    /// cannot be read or written.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            RunError::Solve { .. } => 1,
+            RunError::Usage(_) => 2,
+            RunError::Io { .. } | RunError::Write { .. } => 3,
+        }
+    }
+
+    /// Renders the error with its causes and, for parse errors, the offending input line.
+    pub fn diagnostic(&self) -> String {
+        let mut output = format!("error: {}", self);
+        let mut source = Error::source(self);
+        while let Some(cause) = source {
+            output += &format!("\n  caused by: {}", cause);
+            source = cause.source();
+        }
+        if let RunError::Solve {
+            path,
+            input,
+            source,
+            ..
+        } = self
+        {
+            if let Some(parse_error) = find_parse_error(source.as_ref()) {
+                output += &snippet(path, input, parse_error);
+            }
+        }
+        output
+    }
+}
+
+fn find_parse_error<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a ParseError> {
+    let mut current = Some(error);
+    while let Some(error) = current {
+        if let Some(parse_error) = error.downcast_ref::<ParseError>() {
+            return Some(parse_error);
+        }
+        current = error.source();
+    }
+    None
+}
+
+fn snippet(path: &str, input: &str, error: &ParseError) -> String {
+    let Some(line) = input.lines().nth(error.line.saturating_sub(1)) else {
+        return format!("\n --> {}:{}:{}", path, error.line, error.column);
+    };
+    let gutter = " ".repeat(error.line.to_string().len());
+    format!(
+        "\n{gutter}--> {path}:{line_number}:{column}\n{gutter} |\n{line_number} | {line}\n{gutter} | {caret:>column$}",
+        line_number = error.line,
+        column = error.column,
+        caret = "^",
+    )
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Usage(error) => write!(f, "{}", error),
+            RunError::Io { path, .. } => write!(f, "could not read {}", path),
+            RunError::Write { path, .. } => write!(f, "could not write {}", path),
+            RunError::Solve {
+                day,
+                part: Some(part),
+                source,
+                ..
+            } => write!(f, "day {} part {}: {}", day, part, source),
+            RunError::Solve {
+                day,
+                part: None,
+                source,
+                ..
+            } => write!(f, "day {}: {}", day, source),
+        }
+    }
+}
+
+impl Error for RunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RunError::Io { source, .. } | RunError::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<UsageError> for RunError {
+    fn from(error: UsageError) -> Self {
+        RunError::Usage(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic() {
+        let error = RunError::Solve {
+            day: 9,
+            part: Some(Part::One),
+            path: "day-9/input.txt".to_string(),
+            input: "1 2 3\n4 five 6\n".to_string(),
+            source: Box::new(ParseError::new(2, 3, "expected an integer, found `five`")),
+        };
+        let expected = "error: day 9 part 1: line 2, column 3: expected an integer, found `five`
+ --> day-9/input.txt:2:3
+  |
+2 | 4 five 6
+  |   ^";
+        assert_eq!(error.diagnostic(), expected);
+        assert_eq!(error.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_io_diagnostic() {
+        let error = RunError::Io {
+            path: "missing.txt".to_string(),
+            source: io::Error::new(io::ErrorKind::NotFound, "not found"),
+        };
+        assert_eq!(
+            error.diagnostic(),
+            "error: could not read missing.txt\n  caused by: not found"
+        );
+        assert_eq!(error.exit_code(), 3);
+        let error = RunError::Write {
+            path: "out/loop.svg".to_string(),
+            source: io::Error::new(io::ErrorKind::NotFound, "not found"),
+        };
+        assert_eq!(
+            error.diagnostic(),
+            "error: could not write out/loop.svg\n  caused by: not found"
+        );
+        assert_eq!(error.exit_code(), 3);
+    }
+}
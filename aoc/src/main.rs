@@ -0,0 +1,183 @@
+use std::{env, error::Error, fs, process};
+
+use aoc_common::{Part, Registry, Runner};
+
+mod args;
+mod day10;
+mod day8;
+mod day9;
+mod error;
+
+use args::{Args, UsageError};
+use error::RunError;
+
+const USAGE: &str = "usage: aoc <command> [options]
+
+commands:
+    run     solve one or every registered day
+    list    list the registered days
+    day8    day 8 tools (see below)
+    day9    day 9 tools (see below)
+    day10   day 10 tools (see below)
+
+options for run:
+    --day <N>       day to run (default: every registered day)
+    --part <1|2>    part to run (default: both)
+    --input <PATH>  puzzle input (default: day-<N>/input.txt)
+
+day8 commands (all accept --input <PATH>):
+    walk [--part <1|2>] [--start <PATTERN>] [--goal <PATTERN>] [--max-steps <N>]
+                    walk the network, giving up after N steps
+    export [--format <dot|mermaid>] [--from <NODE>]
+                    print the network graph, or only the part reachable
+                    from NODE
+    trace [--part <1|2>] [--format <jsonl|csv>] [--goals-only]
+          [--start <PATTERN>] [--goal <PATTERN>] [--max-steps <N>]
+                    print every step of the part 1 walk, or of each
+                    ghost until its walk repeats
+    analyze         report components, reachable goals and ghost cycles,
+                    and whether the part 2 lcm shortcut is valid
+
+    a PATTERN is a node name, PRE*, *SUF, or a glob using * and ?; by
+    default part 1 walks AAA to ZZZ and part 2 walks *A to *Z
+
+day9 commands (all accept --input <PATH>):
+    extrapolate [--steps <N>] [--coefficients]
+                    extend every history N steps forward, or backward
+                    for negative N, optionally printing the fitted
+                    polynomial with x = 0 at the first value
+    stream          solve both parts in one pass, reading a line at a
+                    time; --input - reads from stdin
+    show [--line <N>] [--style <plain|ansi>]
+                    draw the difference pyramid of line N, or of every
+                    line, with the extrapolated values at both ends
+
+day10 commands (all accept --input <PATH>):
+    show [--style <plain|ansi>]
+                    draw the pipe map with the main loop in box-drawing
+                    characters and enclosed tiles marked I; ansi also
+                    colours the loop and dims the junk pipes
+    image --output <PATH> [--format <svg|ppm>] [--scale <N>]
+                    draw the main loop, its enclosed tiles and the start
+                    tile as an image with N pixels per tile (default 8)
+    distances [--style <plain|ansi>]
+                    print a heatmap of each tile's distance from the start
+                    in tenths of the maximum, then the farthest tile
+
+exit status:
+    0 on success, 1 for invalid puzzle input, 2 for invalid usage,
+    3 when a file cannot be read or written";
+
+fn registry() -> Registry {
+    Registry::new()
+        .register::<day_6::Day6>()
+        .register::<day_8::Day8>()
+        .register::<day_9::Day9>()
+        .register::<day_10::Day10>()
+        .register::<day_11::Day11>()
+}
+
+/// The `--input` path, or the day's checked-in puzzle input.
+fn input_path(args: &Args, day: u8) -> String {
+    match args.value("input") {
+        Some(path) => path.to_string(),
+        None => format!("day-{}/input.txt", day),
+    }
+}
+
+fn read_input(path: &str) -> Result<String, RunError> {
+    fs::read_to_string(path).map_err(|source| RunError::Io {
+        path: path.to_string(),
+        source,
+    })
+}
+
+/// Reads and parses the day's input, keeping the path and input around for diagnostics.
+fn load<T, E: Into<Box<dyn Error>>>(
+    args: &Args,
+    day: u8,
+    part: Option<Part>,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<(T, String, String), RunError> {
+    let path = input_path(args, day);
+    let input = read_input(&path)?;
+    match parse(&input) {
+        Ok(parsed) => Ok((parsed, path, input)),
+        Err(error) => Err(RunError::solve(day, part, path, input, error)),
+    }
+}
+
+fn run(registry: &Registry, args: &Args) -> Result<(), RunError> {
+    let selected: Vec<&dyn Runner> = match args.parse_value::<u8>("day")? {
+        Some(day) => vec![registry
+            .get(day)
+            .ok_or_else(|| UsageError(format!("day {} is not registered", day)))?],
+        None => registry.iter().collect(),
+    };
+    let parts: Vec<Part> = match args.parse_value::<Part>("part")? {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+    if selected.len() > 1 && args.value("input").is_some() {
+        return Err(UsageError("--input requires --day".into()).into());
+    }
+
+    for runner in selected {
+        let path = input_path(args, runner.day());
+        let input = read_input(&path)?;
+        for &part in &parts {
+            if !runner.has_part(part) {
+                println!("day {} part {}: not implemented", runner.day(), part);
+                continue;
+            }
+            match runner.run(part, &input) {
+                Ok(answer) => println!("day {} part {}: {}", runner.day(), part, answer),
+                Err(source) => {
+                    return Err(RunError::solve(
+                        runner.day(),
+                        Some(part),
+                        path,
+                        input,
+                        source,
+                    ))
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn list(registry: &Registry) {
+    for runner in registry.iter() {
+        let parts = if runner.has_part(Part::Two) {
+            "1, 2"
+        } else {
+            "1"
+        };
+        println!("day {} (parts {})", runner.day(), parts);
+    }
+}
+
+fn main() {
+    let args = Args::parse(env::args().skip(1));
+    let registry = registry();
+    let result = match args.positional(0) {
+        Some("run") => run(&registry, &args),
+        Some("list") => {
+            list(&registry);
+            Ok(())
+        }
+        Some("day8") => day8::run(&args),
+        Some("day9") => day9::run(&args),
+        Some("day10") => day10::run(&args),
+        Some(command) => Err(UsageError(format!("unknown command: {}", command)).into()),
+        None => Err(UsageError("missing command".into()).into()),
+    };
+    if let Err(error) = result {
+        eprintln!("{}", error.diagnostic());
+        if let RunError::Usage(_) = error {
+            eprintln!("\n{}", USAGE);
+        }
+        process::exit(error.exit_code());
+    }
+}
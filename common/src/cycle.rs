@@ -0,0 +1,173 @@
+/// A finite sequence with random access, which is all [`Cyclic`] needs to repeat it.
+pub trait Sequence {
+    type Item;
+
+    fn len(&self) -> usize;
+
+    /// The item at `index`, which is always below [`Sequence::len`].
+    fn get(&self, index: usize) -> Self::Item;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a, T> Sequence for &'a [T] {
+    type Item = &'a T;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn get(&self, index: usize) -> Self::Item {
+        &self[index]
+    }
+}
+
+/// Repeats a sequence forever, like [`Iterator::cycle`], while keeping track of
+/// where in the sequence it is and how many times it has wrapped around.
+///
+/// The position is what makes state-based cycle detection possible: a walk driven
+/// by a repeating sequence is in the same state only if it is at the same place
+/// in the sequence too.
+///
+/// [`Cyclic::step_back`] undoes [`Iterator::next`]. It is deliberately not a
+/// `DoubleEndedIterator`, since that would require the two ends to share one
+/// range without overlap, while here both ends move the same position.
+#[derive(Debug, Clone)]
+pub struct Cyclic<S> {
+    sequence: S,
+    position: usize,
+    cycles_completed: usize,
+}
+
+impl<S: Sequence> Cyclic<S> {
+    pub fn new(sequence: S) -> Self {
+        Self {
+            sequence,
+            position: 0,
+            cycles_completed: 0,
+        }
+    }
+
+    /// Index of the item the next call to `next` yields.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// How many times the whole sequence has been yielded.
+    pub fn cycles_completed(&self) -> usize {
+        self.cycles_completed
+    }
+
+    /// Length of one cycle, i.e. of the underlying sequence.
+    pub fn period(&self) -> usize {
+        self.sequence.len()
+    }
+
+    /// Items left before the sequence starts over.
+    pub fn remaining_in_cycle(&self) -> usize {
+        self.period() - self.position
+    }
+
+    /// Total number of items yielded since the start.
+    pub fn steps(&self) -> usize {
+        self.cycles_completed * self.period() + self.position
+    }
+
+    pub fn reset(&mut self) {
+        self.seek(0);
+    }
+
+    /// Steps back one place and yields the item there, undoing the last `next`, or
+    /// returns `None` once back at the start.
+    pub fn step_back(&mut self) -> Option<S::Item> {
+        if self.steps() == 0 {
+            return None;
+        }
+        if self.position == 0 {
+            self.position = self.period();
+            self.cycles_completed -= 1;
+        }
+        self.position -= 1;
+        Some(self.sequence.get(self.position))
+    }
+
+    /// Jumps to where the iterator would be after yielding `steps` items from the start.
+    pub fn seek(&mut self, steps: usize) {
+        if self.sequence.is_empty() {
+            return;
+        }
+        self.position = steps % self.period();
+        self.cycles_completed = steps / self.period();
+    }
+}
+
+impl<S: Sequence> Iterator for Cyclic<S> {
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.sequence.is_empty() {
+            return None;
+        }
+        let item = self.sequence.get(self.position);
+        self.position += 1;
+        if self.position == self.period() {
+            self.position = 0;
+            self.cycles_completed += 1;
+        }
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITEMS: &[char] = &['a', 'b', 'c'];
+
+    #[test]
+    fn test_cycles() {
+        let mut cyclic = Cyclic::new(ITEMS);
+        let taken: String = cyclic.by_ref().take(7).collect();
+        assert_eq!(taken, "abcabca");
+        assert_eq!(cyclic.position(), 1);
+        assert_eq!(cyclic.cycles_completed(), 2);
+        assert_eq!(cyclic.steps(), 7);
+        assert_eq!(cyclic.period(), 3);
+        assert_eq!(cyclic.remaining_in_cycle(), 2);
+    }
+
+    #[test]
+    fn test_seek_and_reset() {
+        let mut cyclic = Cyclic::new(ITEMS);
+        cyclic.seek(11);
+        assert_eq!((cyclic.position(), cyclic.cycles_completed()), (2, 3));
+        assert_eq!(cyclic.next(), Some(&'c'));
+        assert_eq!(cyclic.position(), 0);
+        cyclic.reset();
+        assert_eq!(cyclic.steps(), 0);
+        assert_eq!(cyclic.next(), Some(&'a'));
+    }
+
+    #[test]
+    fn test_step_back_undoes_next() {
+        let mut cyclic = Cyclic::new(ITEMS);
+        cyclic.seek(4);
+        assert_eq!(cyclic.step_back(), Some(&'a'));
+        assert_eq!(cyclic.step_back(), Some(&'c'));
+        assert_eq!((cyclic.position(), cyclic.cycles_completed()), (2, 0));
+        assert_eq!(cyclic.next(), Some(&'c'));
+        let rest: String = std::iter::from_fn(|| cyclic.step_back()).collect();
+        assert_eq!(rest, "cba");
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut cyclic = Cyclic::new(&[] as &[char]);
+        assert_eq!(cyclic.next(), None);
+        assert_eq!(cyclic.step_back(), None);
+        cyclic.seek(5);
+        assert_eq!(cyclic.steps(), 0);
+    }
+}
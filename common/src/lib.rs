@@ -0,0 +1,10 @@
+mod cycle;
+pub mod math;
+mod parse;
+mod registry;
+mod solution;
+
+pub use cycle::{Cyclic, Sequence};
+pub use parse::{tokens, ParseError};
+pub use registry::{Registry, Runner};
+pub use solution::{ParsePartError, Part, PartTwo, Solution};
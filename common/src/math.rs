@@ -0,0 +1,216 @@
+//! Number theory helpers shared by the days that combine cycle lengths.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple of `a` and `b`, or `None` if it does not fit in a `u64`.
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of every number in `numbers` (1 for an empty slice).
+///
+/// Accumulates in `u128`, so products of many `u64` cycle lengths stay exact;
+/// `None` means the result does not even fit in a `u128`.
+pub fn lcm_of(numbers: &[u64]) -> Option<u128> {
+    numbers.iter().try_fold(1u128, |acc, &number| {
+        let number = number as u128;
+        if acc == 0 || number == 0 {
+            return Some(0);
+        }
+        (acc / wide_gcd(acc, number)).checked_mul(number)
+    })
+}
+
+fn wide_gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Prime factorisation of `n` as `(prime, exponent)` pairs in ascending order.
+pub fn prime_factors(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut divisor = 2;
+    while divisor <= n / divisor {
+        let mut exponent = 0;
+        while n.is_multiple_of(divisor) {
+            n /= divisor;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((divisor, exponent));
+        }
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Returns `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `modulus`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` with the Chinese
+/// Remainder Theorem, allowing moduli that are not pairwise coprime.
+///
+/// Returns `(x, m)` where every solution is `x + k * m` and `0 <= x < m`, or `None`
+/// if the congruences contradict each other or the combined modulus overflows.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences.iter().try_fold(
+        (0i128, 1i128),
+        |(residue, modulus), &(other, other_modulus)| {
+            let (g, x, _) = extended_gcd(modulus, other_modulus);
+            let difference = other - residue;
+            if difference % g != 0 {
+                return None;
+            }
+            let step = other_modulus / g;
+            let k = (difference / g % step)
+                .checked_mul(x % step)?
+                .rem_euclid(step);
+            let combined_modulus = modulus.checked_mul(step)?;
+            let combined = residue
+                .checked_add(modulus.checked_mul(k)?)?
+                .rem_euclid(combined_modulus);
+            Some((combined, combined_modulus))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic xorshift generator, so the property tests are reproducible.
+    struct Numbers(u64);
+
+    impl Numbers {
+        fn next(&mut self, below: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % below
+        }
+    }
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(checked_lcm(4, 6), Some(12));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_of(&[]), Some(1));
+        assert_eq!(lcm_of(&[2, 3, 4]), Some(12));
+        assert_eq!(
+            lcm_of(&[u64::MAX, u64::MAX - 1]),
+            Some(u64::MAX as u128 * (u64::MAX - 1) as u128)
+        );
+    }
+
+    #[test]
+    fn test_lcm_properties() {
+        let mut numbers = Numbers(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..1000 {
+            let a = numbers.next(1_000_000) + 1;
+            let b = numbers.next(1_000_000) + 1;
+            let lcm = lcm_of(&[a, b]).unwrap() as u64;
+            assert_eq!(lcm % a, 0);
+            assert_eq!(lcm % b, 0);
+            assert_eq!(lcm * gcd(a, b), a * b);
+            assert_eq!(checked_lcm(a, b), Some(lcm));
+        }
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(360), vec![(2, 3), (3, 2), (5, 1)]);
+        let mut numbers = Numbers(12345);
+        for _ in 0..1000 {
+            let n = numbers.next(10_000_000) + 1;
+            let factors = prime_factors(n);
+            let product: u64 = factors.iter().map(|(p, e)| p.pow(*e)).product();
+            assert_eq!(product, n);
+            assert!(factors.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            assert!(factors
+                .iter()
+                .all(|(p, _)| prime_factors(*p) == vec![(*p, 1)]));
+        }
+    }
+
+    #[test]
+    fn test_extended_gcd_properties() {
+        let mut numbers = Numbers(42);
+        for _ in 0..1000 {
+            let a = numbers.next(1_000_000) as i128 - 500_000;
+            let b = numbers.next(1_000_000) as i128 - 500_000;
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(a * x + b * y, g);
+            assert_eq!(
+                g as u64,
+                gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64)
+            );
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_crt_properties() {
+        let mut numbers = Numbers(7);
+        for _ in 0..1000 {
+            let x = numbers.next(1_000_000) as i128;
+            let congruences: Vec<(i128, i128)> = (0..3)
+                .map(|_| {
+                    let modulus = numbers.next(1000) as i128 + 1;
+                    (x % modulus, modulus)
+                })
+                .collect();
+            let (solution, modulus) = crt(&congruences).unwrap();
+            assert_eq!(solution, x % modulus);
+            for (residue, other_modulus) in congruences {
+                assert_eq!(modulus % other_modulus, 0);
+                assert_eq!(solution % other_modulus, residue);
+            }
+        }
+    }
+}
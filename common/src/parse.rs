@@ -0,0 +1,54 @@
+use std::{error::Error, fmt};
+
+/// Malformed puzzle input, located by 1-based line and column.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Whitespace separated tokens of `line`, each paired with its 1-based column.
+pub fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - line.as_ptr() as usize + 1, token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let result: Vec<(usize, &str)> = tokens("  10 -3   abc").collect();
+        assert_eq!(result, vec![(3, "10"), (6, "-3"), (11, "abc")]);
+    }
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(3, 7, "expected a number");
+        assert_eq!(error.to_string(), "line 3, column 7: expected a number");
+    }
+}
@@ -0,0 +1,164 @@
+use std::{error::Error, marker::PhantomData};
+
+use crate::{Part, PartTwo, Solution};
+
+/// Object-safe view of a [`Solution`], with the answer rendered as text.
+pub trait Runner {
+    fn day(&self) -> u8;
+    fn has_part(&self, part: Part) -> bool;
+    fn run(&self, part: Part, input: &str) -> Result<String, Box<dyn Error>>;
+}
+
+/// A day that only solves part one.
+struct PartOneOnly<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Runner for PartOneOnly<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn has_part(&self, part: Part) -> bool {
+        part == Part::One
+    }
+
+    fn run(&self, part: Part, input: &str) -> Result<String, Box<dyn Error>> {
+        match part {
+            Part::One => Ok(S::part_one(&S::parse(input)?)?.to_string()),
+            Part::Two => Err(format!("day {} has no part two", S::DAY).into()),
+        }
+    }
+}
+
+struct BothParts<S>(PhantomData<fn() -> S>);
+
+impl<S: PartTwo> Runner for BothParts<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn has_part(&self, _part: Part) -> bool {
+        true
+    }
+
+    fn run(&self, part: Part, input: &str) -> Result<String, Box<dyn Error>> {
+        let input = S::parse(input)?;
+        let answer = match part {
+            Part::One => S::part_one(&input)?,
+            Part::Two => S::part_two(&input)?,
+        };
+        Ok(answer.to_string())
+    }
+}
+
+/// The set of days a runner can dispatch to, kept in day order.
+#[derive(Default)]
+pub struct Registry(Vec<Box<dyn Runner>>);
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: PartTwo + 'static>(self) -> Self {
+        self.insert(Box::new(BothParts::<S>(PhantomData)))
+    }
+
+    /// Registers a day that does not solve part two yet.
+    pub fn register_part_one<S: Solution + 'static>(self) -> Self {
+        self.insert(Box::new(PartOneOnly::<S>(PhantomData)))
+    }
+
+    fn insert(mut self, runner: Box<dyn Runner>) -> Self {
+        self.0.retain(|registered| registered.day() != runner.day());
+        self.0.push(runner);
+        self.0.sort_by_key(|runner| runner.day());
+        self
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn Runner> {
+        self.0
+            .iter()
+            .find(|runner| runner.day() == day)
+            .map(|runner| runner.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Runner> {
+        self.0.iter().map(|runner| runner.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    fn parse_number(input: &str) -> Result<usize, ParseError> {
+        input
+            .trim()
+            .parse()
+            .map_err(|_| ParseError::new(1, 1, "expected a number"))
+    }
+
+    struct Double;
+
+    impl Solution for Double {
+        const DAY: u8 = 2;
+
+        type Input = usize;
+        type Answer = usize;
+        type Error = ParseError;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            parse_number(input)
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+            Ok(input * 2)
+        }
+    }
+
+    struct Square;
+
+    impl Solution for Square {
+        const DAY: u8 = 1;
+
+        type Input = usize;
+        type Answer = usize;
+        type Error = ParseError;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            parse_number(input)
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+            Ok(input * input)
+        }
+    }
+
+    impl PartTwo for Square {
+        fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+            Ok(input * input * input)
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let registry = Registry::new()
+            .register_part_one::<Double>()
+            .register::<Square>();
+        let days: Vec<u8> = registry.iter().map(|runner| runner.day()).collect();
+        assert_eq!(days, vec![1, 2]);
+
+        let double = registry.get(2).unwrap();
+        assert!(!double.has_part(Part::Two));
+        assert_eq!(
+            double.run(Part::Two, "21").unwrap_err().to_string(),
+            "day 2 has no part two"
+        );
+        assert_eq!(double.run(Part::One, "21\n").unwrap(), "42");
+        let error = double.run(Part::One, "abc").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: expected a number");
+        assert_eq!(registry.get(1).unwrap().run(Part::Two, "3").unwrap(), "27");
+        assert!(registry.get(3).is_none());
+    }
+}
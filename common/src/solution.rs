@@ -0,0 +1,52 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePartError;
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(ParsePartError),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The interface every day implements so it can be run without knowing its types.
+/// Days that also solve part two implement [`PartTwo`].
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer: Display;
+    type Error: Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Self::Error>;
+}
+
+pub trait PartTwo: Solution {
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Self::Error>;
+}
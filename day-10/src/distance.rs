@@ -0,0 +1,138 @@
+use std::collections::VecDeque;
+
+use crate::{Board, Direction, Error, Style, TileType};
+
+/// Colours for the ten heatmap bands, from blue near the start to red farthest away.
+const HEAT: [u8; 10] = [21, 27, 33, 39, 45, 49, 118, 190, 214, 196];
+const RESET: &str = "\x1b[0m";
+
+/// Breadth-first distances from the start tile, along pipes that connect to
+/// each other, to every tile reachable that way.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DistanceMap {
+    distances: Vec<Vec<Option<usize>>>,
+    start: (usize, usize),
+    farthest: (usize, usize),
+}
+
+impl DistanceMap {
+    /// The distance from the start to `(x, y)`, or `None` if no pipes lead there.
+    pub fn get(&self, x: usize, y: usize) -> Option<usize> {
+        *self.distances.get(y)?.get(x)?
+    }
+
+    /// The tile farthest from the start, the first one found if several tie.
+    pub fn farthest(&self) -> (usize, usize) {
+        self.farthest
+    }
+
+    pub fn max_distance(&self) -> usize {
+        let (x, y) = self.farthest;
+        self.distances[y][x].unwrap_or(0)
+    }
+
+    /// Draws every reached tile as the tenth of the maximum distance it falls in,
+    /// `0` to `9`, with the start as `S` and the farthest tile as `*`. With ANSI
+    /// colours the bands also shade from blue to red.
+    pub fn render(&self, style: Style) -> String {
+        let max_distance = self.max_distance();
+        let mut output = String::new();
+        for (y, row) in self.distances.iter().enumerate() {
+            for (x, &distance) in row.iter().enumerate() {
+                let Some(distance) = distance else {
+                    output.push(' ');
+                    continue;
+                };
+                let band = distance * HEAT.len() / (max_distance + 1);
+                let symbol = match (x, y) {
+                    coordinates if coordinates == self.start => 'S',
+                    coordinates if coordinates == self.farthest => '*',
+                    _ => char::from_digit(band as u32, 10).unwrap(),
+                };
+                match style {
+                    Style::Plain => output.push(symbol),
+                    Style::Ansi => {
+                        output += &format!("\x1b[38;5;{}m{}{}", HEAT[band], symbol, RESET)
+                    }
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl Board {
+    /// Searches breadth-first from the start tile. A step is only taken between
+    /// two tiles whose pipes point at each other, with the start treated as the
+    /// pipe shape it hides.
+    pub fn distances(&self) -> Result<DistanceMap, Error> {
+        let start_type = self.start_tile_type()?;
+        let start = self.get_start_tile().ok_or(Error::MissingStart)?;
+        let shape = |tile_type: TileType| match tile_type {
+            TileType::Start => start_type,
+            tile_type => tile_type,
+        };
+
+        let mut distances: Vec<Vec<Option<usize>>> =
+            self.0.iter().map(|row| vec![None; row.len()]).collect();
+        distances[start.y][start.x] = Some(0);
+        let mut farthest = (start.x, start.y);
+        let mut farthest_distance = 0;
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((tile, distance)) = queue.pop_front() {
+            if distance > farthest_distance {
+                farthest = (tile.x, tile.y);
+                farthest_distance = distance;
+            }
+            for direction in Direction::ALL {
+                if !shape(tile.tile_type).connects(direction) {
+                    continue;
+                }
+                let Some(next) = self.move_in_direction(tile, direction) else {
+                    continue;
+                };
+                if shape(next.tile_type).connects(direction.invert())
+                    && distances[next.y][next.x].is_none()
+                {
+                    distances[next.y][next.x] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        Ok(DistanceMap {
+            distances,
+            start: (start.x, start.y),
+            farthest,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let board: Board = "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...".parse().unwrap();
+        let distances = board.distances().unwrap();
+        assert_eq!(distances.max_distance(), 8);
+        assert_eq!(distances.farthest(), (4, 2));
+        assert_eq!(distances.get(0, 2), Some(0));
+        assert_eq!(distances.get(1, 1), Some(2));
+        assert_eq!(distances.get(0, 0), None);
+        assert_eq!(distances.get(9, 9), None);
+        let expected = "  45 \n 236 \nS1 7*\n14567\n23   \n";
+        assert_eq!(distances.render(Style::Plain), expected);
+    }
+
+    #[test]
+    fn test_junk_pipes_are_not_reached() {
+        // A junk pipe beside the loop points at it but is never stepped onto.
+        let board: Board = "-S7\n.LJ".parse().unwrap();
+        let distances = board.distances().unwrap();
+        assert_eq!(distances.max_distance(), 2);
+        assert_eq!(distances.farthest(), (2, 1));
+        assert_eq!(distances.get(0, 0), None);
+    }
+}
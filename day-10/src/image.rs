@@ -0,0 +1,184 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{Board, Direction, Error, Loop};
+
+const BACKGROUND: [u8; 3] = [255, 255, 255];
+const INTERIOR: [u8; 3] = [155, 227, 155];
+const PIPE: [u8; 3] = [0, 0, 0];
+const START: [u8; 3] = [220, 40, 40];
+const START_BACKGROUND: [u8; 3] = [255, 200, 200];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ImageFormat {
+    Svg,
+    /// Binary (`P6`) portable pixmap.
+    Ppm,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseImageFormatError;
+
+impl FromStr for ImageFormat {
+    type Err = ParseImageFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "svg" => Ok(ImageFormat::Svg),
+            "ppm" => Ok(ImageFormat::Ppm),
+            _ => Err(ParseImageFormatError),
+        }
+    }
+}
+
+/// Draws the main loop as connected line segments through the tile centres, with
+/// the enclosed tiles filled, everything outside left blank and the start tile
+/// marked. Every tile is `scale` pixels square.
+///
+/// # Panics
+///
+/// If `scale` is zero.
+pub fn render_image(board: &Board, format: ImageFormat, scale: usize) -> Result<Vec<u8>, Error> {
+    assert!(scale > 0, "zero image scale");
+    let main_loop = board.main_loop()?;
+    let enclosed = board.enclosed_tiles(&main_loop);
+    Ok(match format {
+        ImageFormat::Svg => svg(board, &main_loop, &enclosed, scale).into_bytes(),
+        ImageFormat::Ppm => ppm(board, &main_loop, &enclosed, scale),
+    })
+}
+
+/// Draws in tile units through the view box, so tile centres sit at `x + 0.5`.
+fn svg(board: &Board, main_loop: &Loop, enclosed: &[(usize, usize)], scale: usize) -> String {
+    let (width, height) = (board.width(), board.height());
+    let mut output = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+        width * scale,
+        height * scale,
+        width,
+        height
+    );
+    output += &format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        width, height
+    );
+    if !enclosed.is_empty() {
+        let squares: String = enclosed
+            .iter()
+            .map(|(x, y)| format!("M{} {}h1v1h-1z", x, y))
+            .collect();
+        output += &format!("<path d=\"{}\" fill=\"rgb(155,227,155)\"/>\n", squares);
+    }
+    // Straight runs need no points of their own; only the corners are drawn.
+    let mut corners = String::new();
+    let mut previous: Option<Direction> = None;
+    for &((x, y), direction) in main_loop.path() {
+        if previous != Some(direction) {
+            let command = if corners.is_empty() { 'M' } else { 'L' };
+            corners += &format!("{}{}.5 {}.5", command, x, y);
+        }
+        previous = Some(direction);
+    }
+    output += &format!(
+        "<path d=\"{}z\" fill=\"none\" stroke=\"black\" stroke-width=\"0.25\" stroke-linejoin=\"round\"/>\n",
+        corners
+    );
+    if let Some((x, y)) = main_loop.coordinates().next() {
+        output += &format!(
+            "<circle cx=\"{}.5\" cy=\"{}.5\" r=\"0.3\" fill=\"rgb(220,40,40)\"/>\n",
+            x, y
+        );
+    }
+    output += "</svg>\n";
+    output
+}
+
+/// Draws each loop tile as a band a quarter of a tile wide, running from the
+/// centre to the edge on each side the pipe connects.
+fn ppm(board: &Board, main_loop: &Loop, enclosed: &[(usize, usize)], scale: usize) -> Vec<u8> {
+    let (width, height) = (board.width() * scale, board.height() * scale);
+    let mut pixels = BACKGROUND.repeat(width * height);
+    let mut fill = |x: usize, y: usize, color: [u8; 3]| {
+        let offset = (y * width + x) * 3;
+        pixels[offset..offset + 3].copy_from_slice(&color);
+    };
+
+    let enclosed: HashSet<&(usize, usize)> = enclosed.iter().collect();
+    let start = main_loop.coordinates().next();
+    let thickness = (scale / 4).max(1);
+    let low = (scale - thickness) / 2;
+    let high = low + thickness;
+    for y in 0..board.height() {
+        for x in 0..board.width() {
+            let tile_type = main_loop.tile_type(x, y);
+            let (background, pipe) = match tile_type {
+                Some(_) if start == Some((x, y)) => (START_BACKGROUND, START),
+                _ if enclosed.contains(&(x, y)) => (INTERIOR, PIPE),
+                _ => (BACKGROUND, PIPE),
+            };
+            for j in 0..scale {
+                for i in 0..scale {
+                    let across = (low..high).contains(&i);
+                    let along = (low..high).contains(&j);
+                    let on_pipe = tile_type.is_some_and(|tile_type| {
+                        (tile_type.connects(Direction::North) && across && j < high)
+                            || (tile_type.connects(Direction::South) && across && j >= low)
+                            || (tile_type.connects(Direction::West) && along && i < high)
+                            || (tile_type.connects(Direction::East) && along && i >= low)
+                    });
+                    let color = if on_pipe { pipe } else { background };
+                    fill(x * scale + i, y * scale + j, color);
+                }
+            }
+        }
+    }
+
+    let mut output = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    output.extend_from_slice(&pixels);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg() {
+        let board: Board = ".....\n.S-7.\n.|.|.\n.L-J.\n.....".parse().unwrap();
+        let svg = render_image(&board, ImageFormat::Svg, 10).unwrap();
+        let expected = "\
+<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"50\" height=\"50\" viewBox=\"0 0 5 5\">
+<rect width=\"5\" height=\"5\" fill=\"white\"/>
+<path d=\"M2 2h1v1h-1z\" fill=\"rgb(155,227,155)\"/>
+<path d=\"M1.5 1.5L3.5 1.5L3.5 3.5L1.5 3.5z\" fill=\"none\" stroke=\"black\" \
+stroke-width=\"0.25\" stroke-linejoin=\"round\"/>
+<circle cx=\"1.5\" cy=\"1.5\" r=\"0.3\" fill=\"rgb(220,40,40)\"/>
+</svg>
+";
+        assert_eq!(String::from_utf8(svg).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let board: Board = "S7.\nLJ.".parse().unwrap();
+        let ppm = render_image(&board, ImageFormat::Ppm, 4).unwrap();
+        let header = b"P6\n12 8\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 12 * 8 * 3);
+        let pixel = |x: usize, y: usize| {
+            let offset = header.len() + (y * 12 + x) * 3;
+            [ppm[offset], ppm[offset + 1], ppm[offset + 2]]
+        };
+        // At this scale pipes are a pixel wide, one pixel in from the tile's top
+        // left. The start's pipe leaves east and south from there.
+        assert_eq!(pixel(0, 0), START_BACKGROUND);
+        assert_eq!(pixel(1, 1), START);
+        assert_eq!(pixel(3, 1), START);
+        assert_eq!(pixel(1, 3), START);
+        assert_eq!(pixel(0, 1), START_BACKGROUND);
+        assert_eq!(pixel(4, 1), PIPE);
+        assert_eq!(pixel(4, 2), BACKGROUND);
+        assert_eq!(pixel(5, 5), PIPE);
+        assert_eq!(pixel(7, 7), BACKGROUND);
+        assert_eq!(pixel(10, 2), BACKGROUND);
+    }
+}
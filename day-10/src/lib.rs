@@ -0,0 +1,515 @@
+use std::{error, fmt, str::FromStr};
+
+use aoc_common::{ParseError, PartTwo, Solution};
+
+mod distance;
+mod image;
+mod pipe_loop;
+mod render;
+
+pub use distance::DistanceMap;
+pub use image::{render_image, ImageFormat, ParseImageFormatError};
+pub use pipe_loop::Loop;
+pub use render::{render_board, ParseStyleError, Style};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    EmptyInput,
+    Parse(ParseError),
+    MissingStart,
+    NoLoop,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyInput => write!(f, "the input has no tiles"),
+            Error::Parse(error) => write!(f, "invalid pipe map: {}", error),
+            Error::MissingStart => write!(f, "the map has no `S` start tile"),
+            Error::NoLoop => write!(f, "no pipe loop leads back to the start tile"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [North, East, South, West];
+
+    pub fn invert(&self) -> Self {
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+}
+use Direction::*;
+
+/// A pipe's two ends are always stored in [`Direction::ALL`] order, so each
+/// shape has exactly one representation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TileType {
+    Pipe(Direction, Direction),
+    Ground,
+    Start,
+}
+
+impl TileType {
+    /// The pipe joining `a` and `b`, with its ends in order.
+    pub fn pipe(a: Direction, b: Direction) -> Self {
+        TileType::Pipe(a.min(b), a.max(b))
+    }
+
+    /// True if this is a pipe with an end pointing towards `direction`.
+    pub fn connects(&self, direction: Direction) -> bool {
+        matches!(self, TileType::Pipe(a, b) if *a == direction || *b == direction)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseTileTypeError;
+
+impl FromStr for TileType {
+    type Err = ParseTileTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "|" => Ok(TileType::Pipe(North, South)),
+            "-" => Ok(TileType::Pipe(East, West)),
+            "L" => Ok(TileType::Pipe(North, East)),
+            "J" => Ok(TileType::Pipe(North, West)),
+            "7" => Ok(TileType::Pipe(South, West)),
+            "F" => Ok(TileType::Pipe(East, South)),
+            "." => Ok(TileType::Ground),
+            "S" => Ok(TileType::Start),
+            _ => Err(ParseTileTypeError),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Tile {
+    x: usize,
+    y: usize,
+    tile_type: TileType,
+}
+
+impl Tile {
+    fn new(x: usize, y: usize, char: &str) -> Result<Self, ParseTileTypeError> {
+        let tile_type: TileType = char.parse()?;
+        Ok(Self { x, y, tile_type })
+    }
+}
+
+#[derive(Debug)]
+pub struct Board(Vec<Vec<Tile>>);
+
+impl Board {
+    fn get_start_tile(&self) -> Option<&Tile> {
+        let mut start: Option<&Tile> = None;
+        for tile_line in self.0.iter() {
+            for tile in tile_line {
+                if tile.tile_type == TileType::Start {
+                    start = Some(tile);
+                }
+            }
+        }
+        start
+    }
+    fn get_tile_at_location(&self, x: usize, y: usize) -> Option<&Tile> {
+        let row = self.0.get(y)?;
+        row.get(x)
+    }
+
+    fn move_in_direction(&self, current: &Tile, direction: Direction) -> Option<&Tile> {
+        let next: Option<(usize, usize)> = match direction {
+            North => {
+                if current.y == 0 {
+                    None
+                } else {
+                    Some((current.x, current.y - 1))
+                }
+            }
+            East => Some((current.x + 1, current.y)),
+            South => Some((current.x, current.y + 1)),
+            West => {
+                if current.x == 0 {
+                    None
+                } else {
+                    Some((current.x - 1, current.y))
+                }
+            }
+        };
+        match next {
+            Some((x, y)) => self.get_tile_at_location(x, y),
+            None => None,
+        }
+    }
+    fn get_next_tile(
+        &self,
+        current: &Tile,
+        previous_direction: Direction,
+    ) -> Option<(&Tile, Direction)> {
+        let (a_dir, b_dir): (Direction, Direction) = match current.tile_type {
+            TileType::Pipe(a, b) => Some((a, b)),
+            _ => None,
+        }?;
+        if a_dir != previous_direction && b_dir != previous_direction {
+            return None;
+        }
+        let next_dir = if a_dir == previous_direction {
+            b_dir
+        } else {
+            a_dir
+        };
+        let next_tile = self.move_in_direction(current, next_dir)?;
+        Some((next_tile, next_dir.invert()))
+    }
+
+    /// Walks the pipes leaving `start` towards `direction` until they lead back into
+    /// `start`, recording each tile and the direction the walk leaves it by.
+    fn follow_loop(&self, start: &Tile, direction: Direction) -> Option<Loop> {
+        let mut path = vec![((start.x, start.y), direction)];
+        let mut current = (
+            self.move_in_direction(start, direction)?,
+            direction.invert(),
+        );
+        loop {
+            let (tile, previous_direction) = current;
+            let (next_tile, entry_direction) = self.get_next_tile(tile, previous_direction)?;
+            path.push(((tile.x, tile.y), entry_direction.invert()));
+            if (next_tile.x, next_tile.y) == (start.x, start.y) {
+                return Some(Loop::new(path));
+            }
+            current = (next_tile, entry_direction);
+        }
+    }
+
+    /// The number of tiles in the longest row.
+    pub fn width(&self) -> usize {
+        self.0.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The coordinates of the `S` tile.
+    pub fn start(&self) -> Option<(usize, usize)> {
+        self.get_start_tile().map(|tile| (tile.x, tile.y))
+    }
+
+    /// Extracts the loop through the tile at `start`, in walking order.
+    ///
+    /// A pipe tile off the main loop is followed from its first end. For the `S`
+    /// tile, only neighbours with a pipe pointing back at it are tried, and a walk
+    /// only counts once it re-enters the start from another side, so dead-end pipes
+    /// next to it are skipped.
+    pub fn extract_loop(&self, start: (usize, usize)) -> Result<Loop, Error> {
+        let (x, y) = start;
+        let tile = self.get_tile_at_location(x, y).ok_or(Error::NoLoop)?;
+        match tile.tile_type {
+            // The walk can't pass through `S` without knowing its shape, so a pipe
+            // on the main loop takes that loop from its position instead.
+            TileType::Pipe(direction, _) => match self.main_loop() {
+                Ok(main_loop) if main_loop.contains(x, y) => Some(main_loop.starting_at(start)),
+                _ => self.follow_loop(tile, direction),
+            },
+            TileType::Start => Direction::ALL
+                .into_iter()
+                .filter(|&direction| {
+                    self.move_in_direction(tile, direction)
+                        .is_some_and(|next| next.tile_type.connects(direction.invert()))
+                })
+                .find_map(|direction| self.follow_loop(tile, direction)),
+            TileType::Ground => None,
+        }
+        .ok_or(Error::NoLoop)
+    }
+
+    /// The loop through the `S` tile.
+    pub fn main_loop(&self) -> Result<Loop, Error> {
+        self.extract_loop(self.start().ok_or(Error::MissingStart)?)
+    }
+
+    /// The pipe shape hidden under the `S` tile, inferred from the loop through it.
+    pub fn start_tile_type(&self) -> Result<TileType, Error> {
+        self.main_loop().map(|main_loop| main_loop.start_type())
+    }
+
+    /// The tiles enclosed by `main_loop`, row by row.
+    ///
+    /// Scans each row left to right, toggling "inside" whenever a loop pipe with a
+    /// northward connection is crossed, so tiles squeezed between pipes count correctly.
+    pub fn enclosed_tiles(&self, main_loop: &Loop) -> Vec<(usize, usize)> {
+        let start_type = main_loop.start_type();
+        let mut enclosed = Vec::new();
+        for row in &self.0 {
+            let mut inside = false;
+            for tile in row {
+                if !main_loop.contains(tile.x, tile.y) {
+                    if inside {
+                        enclosed.push((tile.x, tile.y));
+                    }
+                    continue;
+                }
+                let tile_type = match tile.tile_type {
+                    TileType::Start => start_type,
+                    tile_type => tile_type,
+                };
+                if tile_type.connects(North) {
+                    inside = !inside;
+                }
+            }
+        }
+        enclosed
+    }
+}
+
+impl FromStr for Board {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let board: Vec<Vec<Tile>> = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .enumerate()
+            .map(|(y, (line_index, line))| {
+                let indent = line.len() - line.trim_start().len();
+                line.trim()
+                    .chars()
+                    .enumerate()
+                    .map(|(index, char)| {
+                        Tile::new(index, y, char.to_string().as_str()).map_err(|_| {
+                            ParseError::new(
+                                line_index + 1,
+                                indent + index + 1,
+                                format!("`{}` is not a pipe, ground or start tile", char),
+                            )
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        if board.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        Ok(Self(board))
+    }
+}
+
+pub fn part_one(board: &Board) -> Result<usize, Error> {
+    Ok(board.distances()?.max_distance())
+}
+
+pub fn part_two(board: &Board) -> Result<usize, Error> {
+    Ok(board.enclosed_tiles(&board.main_loop()?).len())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Board;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input.parse()
+    }
+
+    fn part_one(board: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        part_one(board)
+    }
+}
+
+impl PartTwo for Day10 {
+    fn part_two(board: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        part_two(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_ONE: &str = r"
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+    const TEST_INPUT_TWO: &str = r"
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+    #[test]
+    fn test_part_one() {
+        // Arrange
+
+        // Act
+        let result_one = part_one(&TEST_INPUT_ONE.parse().unwrap()).unwrap();
+
+        // Assert
+        assert_eq!(result_one, 4);
+
+        let result_two = part_one(&TEST_INPUT_TWO.parse().unwrap()).unwrap();
+        assert_eq!(result_two, 8);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!("\n\n".parse::<Board>().unwrap_err(), Error::EmptyInput);
+        assert_eq!(
+            "\n.S-7\n.|x|".parse::<Board>().unwrap_err(),
+            Error::Parse(ParseError::new(
+                3,
+                3,
+                "`x` is not a pipe, ground or start tile"
+            ))
+        );
+        let board: Board = "F-7\n|.|\nL-J".parse().unwrap();
+        assert_eq!(part_one(&board), Err(Error::MissingStart));
+        let board: Board = "FS7\n|.|\nL-.".parse().unwrap();
+        assert_eq!(part_one(&board), Err(Error::NoLoop));
+    }
+
+    #[test]
+    fn test_start_tile_type() {
+        for (input, expected) in [
+            (TEST_INPUT_ONE, TileType::Pipe(East, South)),
+            (TEST_INPUT_TWO, TileType::Pipe(East, South)),
+            (".F-7.\n.|.|.\n.L-S.", TileType::Pipe(North, West)),
+            // The pipe east of the start points back at it but leads nowhere.
+            ("F-S-\n|.|.\nL-J.", TileType::Pipe(South, West)),
+        ] {
+            let board: Board = input.parse().unwrap();
+            assert_eq!(board.start_tile_type(), Ok(expected));
+        }
+        assert_eq!(part_one(&".F-7.\n.|.|.\n.L-S.".parse().unwrap()), Ok(4));
+        assert_eq!(part_one(&"F-S-\n|.|.\nL-J.".parse().unwrap()), Ok(4));
+    }
+
+    #[test]
+    fn test_extract_loop() {
+        let board: Board = TEST_INPUT_ONE.parse().unwrap();
+        let main_loop = board.main_loop().unwrap();
+        assert_eq!(
+            main_loop.path(),
+            [
+                ((1, 1), East),
+                ((2, 1), East),
+                ((3, 1), South),
+                ((3, 2), South),
+                ((3, 3), West),
+                ((2, 3), West),
+                ((1, 3), North),
+                ((1, 2), North),
+            ]
+        );
+        assert!(main_loop.contains(2, 3));
+        assert!(!main_loop.contains(2, 2));
+        assert_eq!(main_loop.tile_type(1, 1), Some(TileType::Pipe(East, South)));
+        assert_eq!(main_loop.tile_type(3, 3), Some(TileType::Pipe(North, West)));
+        // Starting from any pipe on the loop walks the same tiles.
+        let from_pipe = board.extract_loop((3, 2)).unwrap();
+        assert_eq!(from_pipe.len(), 8);
+        assert_eq!(from_pipe.coordinates().next(), Some((3, 2)));
+        assert!(main_loop
+            .coordinates()
+            .all(|(x, y)| from_pipe.contains(x, y)));
+        assert_eq!(board.extract_loop((0, 0)), Err(Error::NoLoop));
+        assert_eq!(board.extract_loop((9, 9)), Err(Error::NoLoop));
+    }
+
+    const TEST_INPUT_THREE: &str = r"
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+    const TEST_INPUT_FOUR: &str = r"
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+    const TEST_INPUT_FIVE: &str = r"
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+    const TEST_INPUT_SIX: &str = r"
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn test_part_two() {
+        for (input, expected) in [
+            (TEST_INPUT_ONE, 1),
+            (TEST_INPUT_THREE, 4),
+            (TEST_INPUT_FOUR, 4),
+            (TEST_INPUT_FIVE, 8),
+            (TEST_INPUT_SIX, 10),
+        ] {
+            let result = part_two(&input.parse().unwrap()).unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use crate::{Direction, TileType};
+
+/// The closed loop of pipes through one tile, in walking order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Loop {
+    /// Every tile on the loop, starting with the tile it was extracted from, and
+    /// the direction the loop leaves it by.
+    path: Vec<((usize, usize), Direction)>,
+    /// Each loop tile's position in `path`.
+    members: HashMap<(usize, usize), usize>,
+}
+
+impl Loop {
+    pub(crate) fn new(path: Vec<((usize, usize), Direction)>) -> Self {
+        let members = path
+            .iter()
+            .enumerate()
+            .map(|(index, &(coordinates, _))| (coordinates, index))
+            .collect();
+        Self { path, members }
+    }
+
+    /// The same loop, walked in the same direction from the tile at `start`.
+    pub(crate) fn starting_at(self, start: (usize, usize)) -> Self {
+        match self.members.get(&start) {
+            Some(&index) => {
+                let mut path = self.path;
+                path.rotate_left(index);
+                Loop::new(path)
+            }
+            None => self,
+        }
+    }
+
+    /// The loop's tiles in order, each with the direction the loop leaves it by.
+    pub fn path(&self) -> &[((usize, usize), Direction)] {
+        &self.path
+    }
+
+    /// The loop's tile coordinates in order.
+    pub fn coordinates(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.path.iter().map(|&(coordinates, _)| coordinates)
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.members.contains_key(&(x, y))
+    }
+
+    pub fn len(&self) -> usize {
+        self.path.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// The pipe shape of the first tile, joining the way the loop leaves it and
+    /// the way it comes back. For the `S` tile this is the shape it hides.
+    pub fn start_type(&self) -> TileType {
+        let (_, leaving) = self.path[0];
+        let (_, arriving) = self.path[self.path.len() - 1];
+        TileType::pipe(leaving, arriving.invert())
+    }
+
+    /// The pipe shape of the tile at `(x, y)` if it is on the loop, with the start
+    /// tile resolved to the shape it hides.
+    pub fn tile_type(&self, x: usize, y: usize) -> Option<TileType> {
+        let index = *self.members.get(&(x, y))?;
+        let (_, leaving) = self.path[index];
+        let (_, arriving) = self.path[(index + self.path.len() - 1) % self.path.len()];
+        Some(TileType::pipe(leaving, arriving.invert()))
+    }
+}
@@ -0,0 +1,130 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{Board, Direction::*, Error, TileType};
+
+const LOOP_COLOR: &str = "\x1b[1;33m";
+const START_COLOR: &str = "\x1b[1;31m";
+const ENCLOSED_COLOR: &str = "\x1b[1;32m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Style {
+    /// Only the loop in box-drawing characters; junk pipes keep their ASCII form.
+    Plain,
+    /// Every pipe in box-drawing characters, with the loop in colour and junk dimmed.
+    Ansi,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseStyleError;
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Style::Plain),
+            "ansi" => Ok(Style::Ansi),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+fn box_drawing(tile_type: TileType) -> char {
+    match tile_type {
+        TileType::Pipe(North, South) => '│',
+        TileType::Pipe(East, West) => '─',
+        TileType::Pipe(North, East) => '└',
+        TileType::Pipe(North, West) => '┘',
+        TileType::Pipe(South, West) => '┐',
+        TileType::Pipe(East, South) => '┌',
+        _ => ' ',
+    }
+}
+
+fn ascii(tile_type: TileType) -> char {
+    match tile_type {
+        TileType::Pipe(North, South) => '|',
+        TileType::Pipe(East, West) => '-',
+        TileType::Pipe(North, East) => 'L',
+        TileType::Pipe(North, West) => 'J',
+        TileType::Pipe(South, West) => '7',
+        TileType::Pipe(East, South) => 'F',
+        TileType::Start => 'S',
+        _ => ' ',
+    }
+}
+
+/// Draws the map with the main loop in box-drawing characters and enclosed tiles
+/// marked `I`. Ground outside the loop is left blank.
+///
+/// In plain text the start tile stays `S` and pipes that are not part of the loop
+/// keep their ASCII form; with ANSI colours the start shows the shape it hides and
+/// stands out from the rest of the loop, and junk pipes are drawn dimmed.
+pub fn render_board(board: &Board, style: Style) -> Result<String, Error> {
+    let main_loop = board.main_loop()?;
+    let enclosed: HashSet<(usize, usize)> = board.enclosed_tiles(&main_loop).into_iter().collect();
+    let start = main_loop.coordinates().next();
+
+    let mut output = String::new();
+    for row in &board.0 {
+        for tile in row {
+            let coordinates = (tile.x, tile.y);
+            let tile_type = main_loop.tile_type(tile.x, tile.y);
+            match (style, tile_type) {
+                (Style::Plain, Some(_)) if Some(coordinates) == start => output.push('S'),
+                (Style::Ansi, Some(tile_type)) if Some(coordinates) == start => {
+                    output += &format!("{}{}{}", START_COLOR, box_drawing(tile_type), RESET)
+                }
+                (Style::Plain, Some(tile_type)) => output.push(box_drawing(tile_type)),
+                (Style::Ansi, Some(tile_type)) => {
+                    output += &format!("{}{}{}", LOOP_COLOR, box_drawing(tile_type), RESET)
+                }
+                (Style::Plain, None) if enclosed.contains(&coordinates) => output.push('I'),
+                (Style::Ansi, None) if enclosed.contains(&coordinates) => {
+                    output += &format!("{}I{}", ENCLOSED_COLOR, RESET)
+                }
+                (Style::Plain, None) => output.push(ascii(tile.tile_type)),
+                (Style::Ansi, None) => match tile.tile_type {
+                    TileType::Pipe(..) => {
+                        output += &format!("{}{}{}", DIM, box_drawing(tile.tile_type), RESET)
+                    }
+                    _ => output.push(ascii(tile.tile_type)),
+                },
+            }
+        }
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_plain() {
+        let board: Board = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF".parse().unwrap();
+        let expected = "\
+-L|F7
+7S─┐|
+L│I│|
+-└─┘|
+L|-JF
+";
+        assert_eq!(render_board(&board, Style::Plain), Ok(expected.to_string()));
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let board: Board = "S7.\nLJ-".parse().unwrap();
+        let expected = "\x1b[1;31m┌\x1b[0m\x1b[1;33m┐\x1b[0m \n\
+            \x1b[1;33m└\x1b[0m\x1b[1;33m┘\x1b[0m\x1b[2m─\x1b[0m\n";
+        assert_eq!(render_board(&board, Style::Ansi), Ok(expected.to_string()));
+        assert_eq!(
+            render_board(&"F7\nLJ".parse().unwrap(), Style::Ansi),
+            Err(Error::MissingStart)
+        );
+    }
+}
@@ -1,4 +1,36 @@
-use std::{env, fs, process};
+use std::{error, fmt};
+
+use aoc_common::{ParseError, PartTwo, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    EmptyInput,
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyInput => write!(f, "the input has no image"),
+            Error::Parse(error) => write!(f, "invalid galaxy image: {}", error),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
 
 #[derive(Debug, PartialEq)]
 struct Location {
@@ -37,12 +69,11 @@ fn get_empty_row_indices(input: &str) -> Vec<usize> {
 fn get_empty_column_indices(input: &str) -> Vec<usize> {
     let mut indices: Vec<usize> = Vec::new();
     let lines: Vec<&str> = input.lines().collect();
-    let line_length = lines.get(0).unwrap().len();
-    let line_count = lines.len();
+    let line_length = lines.first().unwrap().len();
     for x in 0..line_length {
         let mut is_empty = true;
-        for y in 0..line_count {
-            let char = lines[y].chars().nth(x).unwrap();
+        for line in &lines {
+            let char = line.chars().nth(x).unwrap();
             if char == '#' {
                 is_empty = false;
             }
@@ -97,7 +128,7 @@ fn get_locations(input: &str) -> Vec<Location> {
     locations
 }
 
-fn part_one(input: String) -> Result<usize, ()> {
+pub fn part_one(input: String) -> usize {
     let expanded_universe = expand(input);
     let locations = get_locations(expanded_universe.as_str());
     let distance_total: usize = locations
@@ -113,13 +144,13 @@ fn part_one(input: String) -> Result<usize, ()> {
             differences
         })
         .sum();
-    Ok(distance_total)
+    distance_total
 }
 
 fn expand_location(
     initial_location: &Location,
-    empty_row_indices: &Vec<usize>,
-    empty_column_indices: &Vec<usize>,
+    empty_row_indices: &[usize],
+    empty_column_indices: &[usize],
     expansion_factor: usize,
 ) -> Location {
     let column_expansions: usize = empty_column_indices
@@ -130,11 +161,10 @@ fn expand_location(
         .iter()
         .filter(|i| **i < initial_location.y)
         .count();
-    let output = Location {
+    Location {
         x: initial_location.x + column_expansions * expansion_factor - column_expansions,
         y: initial_location.y + row_expansions * expansion_factor - row_expansions,
-    };
-    output
+    }
 }
 
 fn calculate_distances_sum(input: String, factor: usize) -> usize {
@@ -161,27 +191,57 @@ fn calculate_distances_sum(input: String, factor: usize) -> usize {
     distance_total
 }
 
-fn part_two(input: String) -> usize {
+pub fn part_two(input: String) -> usize {
     calculate_distances_sum(input, 1_000_000)
 }
 
-fn main() {
-    let mut args = env::args();
-    let part = args.nth(1).unwrap_or("1".into());
-
-    let input = fs::read_to_string("./input.txt").unwrap();
-    let answer = match part.as_str() {
-        "1" => part_one(input).unwrap(),
-        "2" => part_two(input),
-        _ => {
-            eprintln!(
-                "invalid argument: {}. Valid arguments are \"1\" or \"2\"",
-                part
+/// Checks that the image is a non-empty rectangle of `.` and `#`.
+pub fn parse_image(input: &str) -> Result<String, Error> {
+    let image = input.trim();
+    let lines: Vec<&str> = image.lines().collect();
+    let width = lines.first().ok_or(Error::EmptyInput)?.len();
+    let first_line = input.lines().position(|line| !line.trim().is_empty());
+    let line_offset = first_line.unwrap_or_default() + 1;
+    for (index, line) in lines.iter().enumerate() {
+        if let Some(column) = line.find(|c| c != '.' && c != '#') {
+            return Err(
+                ParseError::new(index + line_offset, column + 1, "expected `.` or `#`").into(),
             );
-            process::exit(2);
         }
-    };
-    println!("{}", answer);
+        if line.len() != width {
+            return Err(ParseError::new(
+                index + line_offset,
+                line.len().min(width) + 1,
+                format!("expected {} columns, found {}", width, line.len()),
+            )
+            .into());
+        }
+    }
+    Ok(image.to_string())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = String;
+    type Answer = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_image(input)
+    }
+
+    fn part_one(universe: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(part_one(universe.clone()))
+    }
+}
+
+impl PartTwo for Day11 {
+    fn part_two(universe: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(part_two(universe.clone()))
+    }
 }
 
 #[cfg(test)]
@@ -231,7 +291,7 @@ mod tests {
         // Arrange
 
         // Act
-        let result_one = part_one(TEST_INPUT_ONE.trim().to_string()).unwrap();
+        let result_one = part_one(TEST_INPUT_ONE.trim().to_string());
 
         // Assert
         assert_eq!(result_one, 374);
@@ -265,4 +325,25 @@ mod tests {
             assert_eq!(result, output);
         }
     }
+
+    #[test]
+    fn test_parse_image() {
+        assert_eq!(
+            parse_image(TEST_INPUT_ONE),
+            Ok(TEST_INPUT_ONE.trim().to_string())
+        );
+        assert_eq!(parse_image("\n \n"), Err(Error::EmptyInput));
+        assert_eq!(
+            parse_image("\n..#\n.x.\n"),
+            Err(Error::Parse(ParseError::new(3, 2, "expected `.` or `#`")))
+        );
+        assert_eq!(
+            parse_image("..#\n.."),
+            Err(Error::Parse(ParseError::new(
+                2,
+                3,
+                "expected 3 columns, found 2"
+            )))
+        );
+    }
 }
@@ -0,0 +1,311 @@
+use std::{error, fmt};
+
+use aoc_common::{tokens, ParseError, PartTwo, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    EmptyInput,
+    Parse(ParseError),
+    Overflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyInput => write!(f, "the input has no races"),
+            Error::Parse(error) => write!(f, "invalid race sheet: {}", error),
+            Error::Overflow => write!(f, "the product of the ways to win overflows a u64"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Race {
+    duration: u64,
+    distance_to_beat: u64,
+}
+
+impl Race {
+    fn distance(&self, hold_time: u64) -> u128 {
+        hold_time as u128 * (self.duration - hold_time) as u128
+    }
+
+    fn wins(&self, hold_time: u64) -> bool {
+        hold_time <= self.duration && self.distance(hold_time) > self.distance_to_beat as u128
+    }
+
+    /// The inclusive range of hold times that beat the record, if any.
+    ///
+    /// The winning times are the integers strictly between the roots of
+    /// `h * (duration - h) = distance_to_beat`. The integer square root gives the
+    /// roots to within one, and the boundary is then nudged until it is exact.
+    pub fn winning_hold_times(&self) -> Option<(u64, u64)> {
+        let duration = self.duration as u128;
+        let discriminant = (duration * duration).checked_sub(4 * self.distance_to_beat as u128)?;
+        let mut low = ((duration - discriminant.isqrt()) / 2) as u64;
+        while low <= self.duration / 2 && !self.wins(low) {
+            low += 1;
+        }
+        while low > 0 && self.wins(low - 1) {
+            low -= 1;
+        }
+        if !self.wins(low) {
+            return None;
+        }
+        Some((low, self.duration - low))
+    }
+
+    pub fn ways_to_win(&self) -> u64 {
+        match self.winning_hold_times() {
+            Some((low, high)) => high - low + 1,
+            None => 0,
+        }
+    }
+}
+
+/// How the numbers on the race sheet are read.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Kerning {
+    /// Every number is its own race.
+    Spaced,
+    /// The spaces are bad kerning: all digits on a line form a single race.
+    Joined,
+}
+
+fn extract_numbers(
+    line: &str,
+    line_number: usize,
+    label: &str,
+    kerning: Kerning,
+) -> Result<Vec<u64>, ParseError> {
+    let mut line_tokens = tokens(line);
+    match line_tokens.next() {
+        Some((_, token)) if token == label => {}
+        _ => {
+            return Err(ParseError::new(
+                line_number,
+                1,
+                format!("expected the line to start with `{}`", label),
+            ))
+        }
+    }
+    let numbers: Vec<(usize, String)> = match kerning {
+        Kerning::Spaced => line_tokens
+            .map(|(column, token)| (column, token.to_string()))
+            .collect(),
+        Kerning::Joined => line_tokens
+            .next()
+            .map(|(column, token)| {
+                let joined: String =
+                    line_tokens.fold(token.to_string(), |acc, (_, token)| acc + token);
+                (column, joined)
+            })
+            .into_iter()
+            .collect(),
+    };
+    numbers
+        .into_iter()
+        .map(|(column, token)| {
+            token.parse().map_err(|_| {
+                let message = if token.bytes().all(|byte| byte.is_ascii_digit()) {
+                    format!("`{}` does not fit in a u64", token)
+                } else {
+                    format!("expected a number, found `{}`", token)
+                };
+                ParseError::new(line_number, column, message)
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct Races(Vec<Race>);
+
+impl Races {
+    pub fn parse(value: &str, kerning: Kerning) -> Result<Self, Error> {
+        let mut lines = value.lines();
+        let durations_line = lines.next().ok_or(Error::EmptyInput)?;
+        let distances_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(2, 1, "missing the `Distance:` line"))?;
+
+        let durations = extract_numbers(durations_line, 1, "Time:", kerning)?;
+        let distances = extract_numbers(distances_line, 2, "Distance:", kerning)?;
+        if durations.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        if durations.len() != distances.len() {
+            return Err(ParseError::new(
+                2,
+                1,
+                format!(
+                    "found {} distances for {} race times",
+                    distances.len(),
+                    durations.len()
+                ),
+            )
+            .into());
+        }
+        let races: Vec<Race> = durations
+            .into_iter()
+            .zip(distances)
+            .map(|(duration, distance)| Race {
+                duration,
+                distance_to_beat: distance,
+            })
+            .collect();
+        Ok(Self(races))
+    }
+}
+
+impl TryFrom<&str> for Races {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value, Kerning::Spaced)
+    }
+}
+
+pub fn part_one(races: &Races) -> Result<u64, Error> {
+    races
+        .0
+        .iter()
+        .try_fold(1u64, |product, race| {
+            product.checked_mul(race.ways_to_win())
+        })
+        .ok_or(Error::Overflow)
+}
+
+pub fn part_two(race: &Races) -> Result<u64, Error> {
+    part_one(race)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    /// One race per number, plus the raw sheet for part two to read with its
+    /// digits joined. Joining can overflow where the separate numbers do not, so
+    /// that reading is left to part two.
+    type Input = (Races, String);
+    type Answer = u64;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok((Races::parse(input, Kerning::Spaced)?, input.to_string()))
+    }
+
+    fn part_one((races, _): &Self::Input) -> Result<Self::Answer, Self::Error> {
+        part_one(races)
+    }
+}
+
+impl PartTwo for Day6 {
+    fn part_two((_, sheet): &Self::Input) -> Result<Self::Answer, Self::Error> {
+        part_two(&Races::parse(sheet, Kerning::Joined)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = r"Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn test_part_one() {
+        let races = Races::parse(TEST_INPUT, Kerning::Spaced).unwrap();
+        assert_eq!(part_one(&races), Ok(288));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let race = Races::parse(TEST_INPUT, Kerning::Joined).unwrap();
+        assert_eq!(
+            race.0,
+            vec![Race {
+                duration: 71530,
+                distance_to_beat: 940200
+            }]
+        );
+        assert_eq!(part_two(&race), Ok(71503));
+    }
+
+    #[test]
+    fn test_ways_to_win_matches_brute_force() {
+        for duration in 0..60 {
+            for distance_to_beat in 0..(duration * duration / 4 + 3) {
+                let race = Race {
+                    duration,
+                    distance_to_beat,
+                };
+                let expected = (0..=duration)
+                    .filter(|time| time * (duration - time) > distance_to_beat)
+                    .count() as u64;
+                assert_eq!(race.ways_to_win(), expected, "{:?}", race);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ways_to_win_large_race() {
+        let race = Race {
+            duration: u64::MAX / 2,
+            distance_to_beat: u64::MAX - 1,
+        };
+        let (low, high) = race.winning_hold_times().unwrap();
+        assert!(race.wins(low) && !race.wins(low - 1));
+        assert!(race.wins(high) && !race.wins(high + 1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Races::try_from("").unwrap_err(), Error::EmptyInput);
+        assert_eq!(
+            Races::try_from("Time: 7 15\nDistance: 9 4O").unwrap_err(),
+            Error::Parse(ParseError::new(2, 13, "expected a number, found `4O`"))
+        );
+        assert_eq!(
+            Races::try_from("Time: 7 15\nDistance: 9").unwrap_err(),
+            Error::Parse(ParseError::new(2, 1, "found 1 distances for 2 race times"))
+        );
+    }
+
+    #[test]
+    fn test_joined_overflow_only_fails_part_two() {
+        let input = Day6::parse(
+            "Time: 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5 5\n\
+             Distance: 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0",
+        )
+        .unwrap();
+        assert_eq!(Day6::part_one(&input), Ok(4u64.pow(21)));
+        assert_eq!(
+            Day6::part_two(&input),
+            Err(Error::Parse(ParseError::new(
+                1,
+                7,
+                "`555555555555555555555` does not fit in a u64"
+            )))
+        );
+        let input = Day6::parse("Time: 99999999999 99999999999\nDistance: 1 1").unwrap();
+        assert_eq!(Day6::part_one(&input), Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,296 @@
+use std::fmt;
+
+use aoc_common::Part;
+
+use crate::{reachable_from, Error, GhostCycle, Graph, Network, NodeId, Walker};
+
+/// The strongly connected components of the network, found with Tarjan's
+/// algorithm. Components come out in reverse topological order: no component
+/// has an edge into one listed after it.
+pub fn strongly_connected_components(graph: &Graph) -> Vec<Vec<NodeId>> {
+    const UNVISITED: usize = usize::MAX;
+    let mut index = vec![UNVISITED; graph.len()];
+    let mut low = vec![0; graph.len()];
+    let mut on_stack = vec![false; graph.len()];
+    let mut stack: Vec<NodeId> = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+    for root in graph.ids() {
+        if index[root as usize] != UNVISITED {
+            continue;
+        }
+        // Explicit call stack of (node, successors explored so far), so long
+        // chains of nodes cannot overflow the real one.
+        let mut calls = vec![(root, 0)];
+        index[root as usize] = next_index;
+        low[root as usize] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root as usize] = true;
+        while let Some(&(node, explored)) = calls.last() {
+            if explored < 2 {
+                calls.last_mut().unwrap().1 += 1;
+                let successor = if explored == 0 {
+                    graph.left(node)
+                } else {
+                    graph.right(node)
+                };
+                if index[successor as usize] == UNVISITED {
+                    index[successor as usize] = next_index;
+                    low[successor as usize] = next_index;
+                    next_index += 1;
+                    stack.push(successor);
+                    on_stack[successor as usize] = true;
+                    calls.push((successor, 0));
+                } else if on_stack[successor as usize] {
+                    low[node as usize] = low[node as usize].min(index[successor as usize]);
+                }
+                continue;
+            }
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                low[parent as usize] = low[parent as usize].min(low[node as usize]);
+            }
+            if low[node as usize] == index[node as usize] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member as usize] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+/// True if a walk can stay inside `component` forever.
+fn is_cyclic(graph: &Graph, component: &[NodeId]) -> bool {
+    match component {
+        [node] => graph.left(*node) == *node || graph.right(*node) == *node,
+        _ => true,
+    }
+}
+
+/// What one `..A` start can reach and how its walk repeats.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StartAnalysis {
+    pub start: String,
+    /// Number of nodes reachable from the start, including itself.
+    pub reachable: usize,
+    /// Every reachable `..Z` node with the size of its component, in breadth-first order.
+    pub goals: Vec<(String, usize)>,
+    pub cycle: GhostCycle,
+}
+
+impl StartAnalysis {
+    /// Why the LCM of cycle lengths is not this ghost's answer, if it is not.
+    fn misalignment(&self) -> Option<String> {
+        let cycle = &self.cycle;
+        if cycle.is_aligned() {
+            None
+        } else if cycle.prefix_hits.is_empty() && cycle.cycle_hits.is_empty() {
+            Some("never reaches a goal".to_string())
+        } else if !cycle.prefix_hits.is_empty() {
+            Some("reaches a goal before its cycle starts".to_string())
+        } else if cycle.cycle_hits.len() > 1 {
+            Some(format!(
+                "reaches {} goals per cycle",
+                cycle.cycle_hits.len()
+            ))
+        } else if cycle.cycle_hits[0].is_multiple_of(cycle.cycle_length) {
+            Some(format!(
+                "first goal at step {}, after the first full cycle",
+                cycle.cycle_hits[0]
+            ))
+        } else {
+            Some(format!(
+                "reaches its goal at step {}, not a multiple of its cycle length",
+                cycle.cycle_hits[0]
+            ))
+        }
+    }
+}
+
+/// A structural report on the network explaining whether the part two LCM
+/// shortcut is valid for it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Analysis {
+    pub nodes: usize,
+    pub instructions: usize,
+    pub components: usize,
+    /// Sizes of the components a walk can stay inside forever, largest first.
+    pub cyclic_components: Vec<usize>,
+    pub starts: Vec<StartAnalysis>,
+}
+
+impl Analysis {
+    /// True when every ghost is aligned, so the LCM of the cycle lengths is the answer.
+    pub fn lcm_applies(&self) -> bool {
+        self.starts.iter().all(|start| start.cycle.is_aligned())
+    }
+}
+
+pub fn analyze(network: &Network) -> Result<Analysis, Error> {
+    let graph = &network.graph;
+    let components = strongly_connected_components(graph);
+    let mut component_size = vec![0; graph.len()];
+    for component in &components {
+        for &node in component {
+            component_size[node as usize] = component.len();
+        }
+    }
+    let mut cyclic_components: Vec<usize> = components
+        .iter()
+        .filter(|component| is_cyclic(graph, component))
+        .map(Vec::len)
+        .collect();
+    cyclic_components.sort_unstable_by(|a, b| b.cmp(a));
+    let walker = Walker::for_part(network, Part::Two);
+    let starts = walker
+        .starts()?
+        .into_iter()
+        .map(|start| {
+            let reachable = reachable_from(graph, start);
+            let goals = reachable
+                .iter()
+                .filter(|&&node| walker.is_goal(node))
+                .map(|&node| (graph.name(node).to_string(), component_size[node as usize]))
+                .collect();
+            Ok(StartAnalysis {
+                start: graph.name(start).to_string(),
+                reachable: reachable.len(),
+                goals,
+                cycle: walker.find_cycle(start, |_, _, _| {})?,
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+    Ok(Analysis {
+        nodes: graph.len(),
+        instructions: network.instructions.len(),
+        components: components.len(),
+        cyclic_components,
+        starts,
+    })
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "network: {} nodes, {} instructions",
+            self.nodes, self.instructions
+        )?;
+        writeln!(
+            f,
+            "components: {} ({} cyclic, sizes {:?})",
+            self.components,
+            self.cyclic_components.len(),
+            self.cyclic_components
+        )?;
+        for start in &self.starts {
+            let goals: Vec<String> = start
+                .goals
+                .iter()
+                .map(|(name, size)| format!("{} (component of {})", name, size))
+                .collect();
+            writeln!(
+                f,
+                "{}: reaches {} nodes, goals: {}",
+                start.start,
+                start.reachable,
+                if goals.is_empty() {
+                    "none".to_string()
+                } else {
+                    goals.join(", ")
+                }
+            )?;
+            let cycle = &start.cycle;
+            writeln!(
+                f,
+                "    cycle of {} steps from step {}, prefix hits {:?}, cycle hits {:?}",
+                cycle.cycle_length, cycle.cycle_start, cycle.prefix_hits, cycle.cycle_hits
+            )?;
+        }
+        let reasons: Vec<String> = self
+            .starts
+            .iter()
+            .filter_map(|start| Some(format!("{} {}", start.start, start.misalignment()?)))
+            .collect();
+        if reasons.is_empty() {
+            write!(
+                f,
+                "lcm shortcut: applies, every ghost reaches one goal once per cycle, first at its cycle length"
+            )
+        } else {
+            write!(f, "lcm shortcut: does not apply: {}", reasons.join("; "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        parse_input,
+        tests::{LATE_ALIGNED_INPUT, TEST_INPUT_TWO},
+    };
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = parse_input(TEST_INPUT_TWO).unwrap().graph;
+        let names = |component: &Vec<NodeId>| {
+            let mut names: Vec<&str> = component.iter().map(|&id| graph.name(id)).collect();
+            names.sort_unstable();
+            names
+        };
+        let components: Vec<Vec<&str>> = strongly_connected_components(&graph)
+            .iter()
+            .map(names)
+            .collect();
+        assert_eq!(
+            components,
+            vec![
+                vec!["XXX"],
+                vec!["11B", "11Z"],
+                vec!["11A"],
+                vec!["22B", "22C", "22Z"],
+                vec!["22A"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze() {
+        let analysis = analyze(&parse_input(TEST_INPUT_TWO).unwrap()).unwrap();
+        assert_eq!(analysis.components, 5);
+        assert_eq!(analysis.cyclic_components, vec![3, 2, 1]);
+        assert_eq!(analysis.starts[0].reachable, 4);
+        assert_eq!(analysis.starts[0].goals, vec![("11Z".to_string(), 2)]);
+        assert!(!analysis.lcm_applies());
+        let report = analysis.to_string();
+        assert!(report.contains("22A: reaches 5 nodes, goals: 22Z (component of 3)\n"));
+        assert!(report.ends_with("lcm shortcut: does not apply: 22A reaches 2 goals per cycle"));
+    }
+
+    #[test]
+    fn test_analyze_aligned() {
+        let network =
+            parse_input("LR\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11B, 11B)\n").unwrap();
+        let analysis = analyze(&network).unwrap();
+        assert!(analysis.lcm_applies());
+        assert!(analysis.to_string().ends_with("lcm shortcut: applies, every ghost reaches one goal once per cycle, first at its cycle length"));
+    }
+
+    #[test]
+    fn test_analyze_late_aligned() {
+        let analysis = analyze(&parse_input(LATE_ALIGNED_INPUT).unwrap()).unwrap();
+        assert!(!analysis.lcm_applies());
+        assert!(analysis.to_string().ends_with(
+            "lcm shortcut: does not apply: 11A first goal at step 6, after the first full cycle"
+        ));
+    }
+}
@@ -0,0 +1,159 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{Graph, NodeId};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Dot,
+    Mermaid,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseFormatError;
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dot" => Ok(Format::Dot),
+            "mermaid" => Ok(Format::Mermaid),
+            _ => Err(ParseFormatError),
+        }
+    }
+}
+
+/// Every node reachable from `start` (including `start`), in breadth-first order.
+pub fn reachable_from(graph: &Graph, start: NodeId) -> Vec<NodeId> {
+    let mut seen = vec![false; graph.len()];
+    let mut order = vec![start];
+    seen[start as usize] = true;
+    let mut next = 0;
+    while let Some(&id) = order.get(next) {
+        for neighbour in [graph.left(id), graph.right(id)] {
+            if !seen[neighbour as usize] {
+                seen[neighbour as usize] = true;
+                order.push(neighbour);
+            }
+        }
+        next += 1;
+    }
+    order
+}
+
+/// The outgoing edges of `id`, merging left and right when they lead to the same node.
+fn edges(graph: &Graph, id: NodeId) -> Vec<(NodeId, &'static str)> {
+    let (left, right) = (graph.left(id), graph.right(id));
+    if left == right {
+        vec![(left, "L/R")]
+    } else {
+        vec![(left, "L"), (right, "R")]
+    }
+}
+
+/// Renders the network, or only the part reachable from `from`, as a Graphviz
+/// digraph or a Mermaid flowchart. `..A` start nodes are drawn green and `..Z`
+/// goal nodes red.
+pub fn export(graph: &Graph, format: Format, from: Option<NodeId>) -> String {
+    let nodes: Vec<NodeId> = match from {
+        Some(start) => reachable_from(graph, start),
+        None => graph.ids().collect(),
+    };
+    match format {
+        Format::Dot => to_dot(graph, &nodes),
+        Format::Mermaid => to_mermaid(graph, &nodes),
+    }
+}
+
+fn to_dot(graph: &Graph, nodes: &[NodeId]) -> String {
+    let mut output = String::from("digraph network {\n");
+    for &id in nodes {
+        let name = graph.name(id);
+        let color = if name.ends_with('A') {
+            Some("palegreen")
+        } else if name.ends_with('Z') {
+            Some("lightcoral")
+        } else {
+            None
+        };
+        if let Some(color) = color {
+            writeln!(
+                output,
+                "    \"{}\" [style=filled, fillcolor={}];",
+                name, color
+            )
+            .unwrap();
+        }
+    }
+    for &id in nodes {
+        for (target, label) in edges(graph, id) {
+            writeln!(
+                output,
+                "    \"{}\" -> \"{}\" [label=\"{}\"];",
+                graph.name(id),
+                graph.name(target),
+                label
+            )
+            .unwrap();
+        }
+    }
+    output.push_str("}\n");
+    output
+}
+
+fn to_mermaid(graph: &Graph, nodes: &[NodeId]) -> String {
+    let mut output = String::from("flowchart LR\n");
+    for &id in nodes {
+        writeln!(output, "    n{}[\"{}\"]", id, graph.name(id)).unwrap();
+    }
+    for &id in nodes {
+        for (target, label) in edges(graph, id) {
+            writeln!(output, "    n{} -->|{}| n{}", id, label, target).unwrap();
+        }
+    }
+    output.push_str("    classDef start fill:#9f9\n    classDef goal fill:#f99\n");
+    for &id in nodes {
+        let name = graph.name(id);
+        if name.ends_with('A') {
+            writeln!(output, "    class n{} start", id).unwrap();
+        } else if name.ends_with('Z') {
+            writeln!(output, "    class n{} goal", id).unwrap();
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, tests::TEST_INPUT_TWO};
+
+    #[test]
+    fn test_export_dot() {
+        let graph = parse_input(TEST_INPUT_TWO).unwrap().graph;
+        let start = graph.id("22A").unwrap();
+        let expected = r#"digraph network {
+    "22A" [style=filled, fillcolor=palegreen];
+    "22Z" [style=filled, fillcolor=lightcoral];
+    "22A" -> "22B" [label="L"];
+    "22A" -> "XXX" [label="R"];
+    "22B" -> "22C" [label="L/R"];
+    "XXX" -> "XXX" [label="L/R"];
+    "22C" -> "22Z" [label="L/R"];
+    "22Z" -> "22B" [label="L/R"];
+}
+"#;
+        assert_eq!(export(&graph, Format::Dot, Some(start)), expected);
+    }
+
+    #[test]
+    fn test_export_mermaid() {
+        let graph = parse_input(TEST_INPUT_TWO).unwrap().graph;
+        let output = export(&graph, Format::Mermaid, None);
+        assert!(output.starts_with("flowchart LR\n    n0[\"11A\"]\n"));
+        assert!(output.contains("    n0 -->|L| n1\n    n0 -->|R| n7\n"));
+        assert!(output.contains("    class n0 start\n"));
+        assert!(output.contains("    class n2 goal\n"));
+        assert_eq!(output.matches("-->").count(), 12);
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use aoc_common::{ParseError, Sequence};
+
+use crate::{parse::NodeEntry, Instruction};
+
+/// Dense index of a node in a [`Graph`].
+pub type NodeId = u32;
+
+const RADIX: u32 = 36;
+const PACKED_NODES: usize = (RADIX * RADIX * RADIX) as usize;
+const ABSENT: NodeId = NodeId::MAX;
+
+/// Packs a three character `[0-9A-Z]` node name into a base-36 number.
+pub fn pack(name: &str) -> Option<u16> {
+    let bytes = name.as_bytes();
+    if bytes.len() != 3 {
+        return None;
+    }
+    bytes.iter().try_fold(0u16, |acc, &byte| {
+        let digit = match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'A'..=b'Z' => byte - b'A' + 10,
+            _ => return None,
+        };
+        Some(acc * RADIX as u16 + digit as u16)
+    })
+}
+
+/// The instruction string as a bit vector, with set bits meaning right.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Instructions {
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl Instructions {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Instruction {
+        if self.bits[index / 64] >> (index % 64) & 1 == 1 {
+            Instruction::Right
+        } else {
+            Instruction::Left
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Instruction> + '_ {
+        (0..self.len).map(|index| self.get(index))
+    }
+}
+
+impl Sequence for &Instructions {
+    type Item = Instruction;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, index: usize) -> Instruction {
+        Instructions::get(self, index)
+    }
+}
+
+impl FromIterator<Instruction> for Instructions {
+    fn from_iter<I: IntoIterator<Item = Instruction>>(iter: I) -> Self {
+        let mut instructions = Self::default();
+        for instruction in iter {
+            if instructions.len % 64 == 0 {
+                instructions.bits.push(0);
+            }
+            if instruction == Instruction::Right {
+                instructions.bits[instructions.len / 64] |= 1 << (instructions.len % 64);
+            }
+            instructions.len += 1;
+        }
+        instructions
+    }
+}
+
+/// Maps node names to dense ids: three character `[0-9A-Z]` names go through a
+/// base-36 lookup table, anything else through a hash map.
+#[derive(Debug)]
+struct Interner {
+    packed: Vec<NodeId>,
+    other: HashMap<String, NodeId>,
+}
+
+impl Interner {
+    fn new() -> Self {
+        Self {
+            packed: vec![ABSENT; PACKED_NODES],
+            other: HashMap::new(),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<NodeId> {
+        let id = match pack(name) {
+            Some(packed) => self.packed[packed as usize],
+            None => *self.other.get(name)?,
+        };
+        (id != ABSENT).then_some(id)
+    }
+
+    fn insert(&mut self, name: &str, id: NodeId) {
+        match pack(name) {
+            Some(packed) => self.packed[packed as usize] = id,
+            None => {
+                self.other.insert(name.to_string(), id);
+            }
+        }
+    }
+}
+
+/// The node network with names interned to dense ids and edges in flat arrays,
+/// so walking it is pure indexing.
+#[derive(Debug)]
+pub struct Graph {
+    names: Vec<String>,
+    left: Vec<NodeId>,
+    right: Vec<NodeId>,
+    ids: Interner,
+}
+
+impl Graph {
+    /// Builds the graph from parsed node lines, rejecting duplicate definitions
+    /// and edges to nodes that are never defined.
+    pub fn build(entries: &[NodeEntry]) -> Result<Self, ParseError> {
+        let mut ids = Interner::new();
+        let mut names = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let name = &entry.name.text;
+            if let Some(id) = ids.get(name) {
+                return Err(ParseError::new(
+                    entry.line,
+                    entry.name.column,
+                    format!(
+                        "node `{}` is already defined on line {}",
+                        name, entries[id as usize].line
+                    ),
+                ));
+            }
+            ids.insert(name, names.len() as NodeId);
+            names.push(name.clone());
+        }
+        let mut left = Vec::with_capacity(entries.len());
+        let mut right = Vec::with_capacity(entries.len());
+        for entry in entries {
+            for (token, edges) in [(&entry.left, &mut left), (&entry.right, &mut right)] {
+                let id = ids.get(&token.text).ok_or_else(|| {
+                    ParseError::new(
+                        entry.line,
+                        token.column,
+                        format!("node `{}` is never defined", token.text),
+                    )
+                })?;
+                edges.push(id);
+            }
+        }
+        Ok(Self {
+            names,
+            left,
+            right,
+            ids,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name)
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id as usize]
+    }
+
+    /// Every node id, in the order the nodes were defined.
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        0..self.names.len() as NodeId
+    }
+
+    pub fn left(&self, id: NodeId) -> NodeId {
+        self.left[id as usize]
+    }
+
+    pub fn right(&self, id: NodeId) -> NodeId {
+        self.right[id as usize]
+    }
+
+    pub fn next(&self, id: NodeId, instruction: Instruction) -> NodeId {
+        match instruction {
+            Instruction::Left => self.left(id),
+            Instruction::Right => self.right(id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, Error};
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(pack("000"), Some(0));
+        assert_eq!(pack("00Z"), Some(35));
+        assert_eq!(pack("ZZZ"), Some(PACKED_NODES as u16 - 1));
+        assert_eq!(pack("AAZ"), Some(10 * 36 * 36 + 10 * 36 + 35));
+        assert_eq!(pack("11A"), Some(36 * 36 + 36 + 10));
+        assert_eq!(pack("aaa"), None);
+        assert_eq!(pack("AAAA"), None);
+        assert_eq!(pack("A-A"), None);
+    }
+
+    #[test]
+    fn test_instructions() {
+        let pattern = [Instruction::Left, Instruction::Right, Instruction::Right];
+        let instructions: Instructions = pattern.iter().copied().cycle().take(130).collect();
+        assert_eq!(instructions.len(), 130);
+        assert_eq!(instructions.get(0), Instruction::Left);
+        assert_eq!(instructions.get(64), Instruction::Right);
+        assert_eq!(instructions.get(129), Instruction::Left);
+        assert!(instructions
+            .iter()
+            .zip(pattern.iter().cycle())
+            .all(|(a, b)| a == *b));
+    }
+
+    #[test]
+    fn test_build() {
+        let graph =
+            parse_input("L\n\nAAA = (BBB, AAA)\nBBB = (AAA, long_name)\nlong_name = (AAA, BBB)")
+                .unwrap()
+                .graph;
+        let aaa = graph.id("AAA").unwrap();
+        let bbb = graph.id("BBB").unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.next(aaa, Instruction::Left), bbb);
+        assert_eq!(graph.name(graph.right(bbb)), "long_name");
+        assert_eq!(graph.left(graph.id("long_name").unwrap()), aaa);
+        assert_eq!(graph.id("CCC"), None);
+    }
+
+    #[test]
+    fn test_build_errors() {
+        assert_eq!(
+            parse_input("L\n\nAAA = (BBB, AAA)\nBBB = (AAA, ZZZ)").unwrap_err(),
+            Error::Parse(ParseError::new(4, 13, "node `ZZZ` is never defined"))
+        );
+        assert_eq!(
+            parse_input("L\n\nAAA = (AAA, AAA)\n\nAAA = (AAA, AAA)").unwrap_err(),
+            Error::Parse(ParseError::new(
+                5,
+                1,
+                "node `AAA` is already defined on line 3"
+            ))
+        );
+    }
+}
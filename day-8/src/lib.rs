@@ -0,0 +1,635 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error, fmt,
+    str::FromStr,
+};
+
+use aoc_common::{math, Cyclic, ParseError, Part, PartTwo, Solution};
+
+mod analysis;
+mod export;
+mod graph;
+mod parse;
+mod pattern;
+mod trace;
+
+pub use analysis::{analyze, strongly_connected_components, Analysis, StartAnalysis};
+pub use export::{export, reachable_from, Format, ParseFormatError};
+pub use graph::{Graph, Instructions, NodeId};
+pub use parse::{parse_input, parse_node, NodeEntry, Token};
+pub use pattern::{NodePattern, ParsePatternError};
+pub use trace::{render_trace, trace, ParseTraceFormatError, TraceFormat, TraceStep};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    EmptyInput,
+    Parse(ParseError),
+    MissingStart(String),
+    MissingGoal(String),
+    Unreachable { start: String, goal: String },
+    StepBudgetExceeded(usize),
+    NeverSynchronised,
+    Overflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyInput => write!(f, "the input has no instructions"),
+            Error::Parse(error) => write!(f, "invalid network: {}", error),
+            Error::MissingStart(name) => write!(f, "missing start node `{}`", name),
+            Error::MissingGoal(name) => write!(f, "missing goal node `{}`", name),
+            Error::Unreachable { start, goal } => {
+                write!(f, "`{}` can never be reached from `{}`", goal, start)
+            }
+            Error::StepBudgetExceeded(steps) => {
+                write!(f, "gave up after {} steps without reaching a goal", steps)
+            }
+            Error::NeverSynchronised => {
+                write!(f, "the ghosts are never on goal nodes at the same time")
+            }
+            Error::Overflow => write!(f, "the step count does not fit in 128 bits"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Left => write!(f, "L"),
+            Instruction::Right => write!(f, "R"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InstructionParseError;
+
+impl FromStr for Instruction {
+    type Err = InstructionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(InstructionParseError),
+        }
+    }
+}
+
+pub fn part_one(network: &Network) -> Result<usize, Error> {
+    steps_to_goal(network, None)
+}
+
+/// Walks from `AAA` to `ZZZ`, giving up after `max_steps` steps if given.
+///
+/// A walk that returns to a (node, instruction index) state it has already been in
+/// is looping, so the goal is reported unreachable instead of spinning forever.
+pub fn steps_to_goal(network: &Network, max_steps: Option<usize>) -> Result<usize, Error> {
+    let walker = Walker::for_part(network, Part::One).max_steps(max_steps);
+    walker.walk_to_goal(walker.walk_starts()?[0], |_, _, _| {})
+}
+
+/// The goal hits of one ghost, split into a one-off prefix and a repeating cycle.
+///
+/// The walk's state is the current node plus the index of the next instruction, so
+/// it must eventually revisit a state and repeat forever from there.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GhostCycle {
+    /// Step at which the repeating part of the walk begins.
+    pub cycle_start: usize,
+    pub cycle_length: usize,
+    /// Goal hits before `cycle_start`; these never happen again.
+    pub prefix_hits: Vec<usize>,
+    /// Goal hits in `cycle_start..cycle_start + cycle_length`; these recur every cycle.
+    pub cycle_hits: Vec<usize>,
+}
+
+impl GhostCycle {
+    fn is_hit(&self, step: usize) -> bool {
+        if step < self.cycle_start {
+            return self.prefix_hits.contains(&step);
+        }
+        let offset = (step - self.cycle_start) % self.cycle_length;
+        self.cycle_hits.contains(&(self.cycle_start + offset))
+    }
+
+    /// True when the only goal hit is at `cycle_length` and so repeats exactly every
+    /// `cycle_length` steps from the start, which is the case the plain LCM of cycle
+    /// lengths relies on. A first hit at a later multiple would make the LCM too early.
+    pub fn is_aligned(&self) -> bool {
+        self.prefix_hits.is_empty()
+            && self.cycle_hits.len() == 1
+            && self.cycle_hits[0] == self.cycle_length
+    }
+}
+
+/// The first step at which every ghost is on a goal at the same time.
+fn first_common_hit(cycles: &[GhostCycle]) -> Result<u128, Error> {
+    // The ghost that settles into its cycle last; with no ghosts nothing has to line up.
+    let Some(latest) = cycles.iter().max_by_key(|cycle| cycle.cycle_start) else {
+        return Ok(0);
+    };
+    if cycles.iter().all(GhostCycle::is_aligned) {
+        let lengths: Vec<u64> = cycles
+            .iter()
+            .map(|cycle| cycle.cycle_length as u64)
+            .collect();
+        return math::lcm_of(&lengths).ok_or(Error::Overflow);
+    }
+
+    // Before the last ghost settles into its cycle, only its prefix hits can match.
+    if let Some(&step) = latest
+        .prefix_hits
+        .iter()
+        .find(|&&step| cycles.iter().all(|cycle| cycle.is_hit(step)))
+    {
+        return Ok(step as u128);
+    }
+
+    // Afterwards every ghost is periodic: solve each combination of cycle hits with CRT.
+    let settled = latest.cycle_start as i128;
+    let mut combinations: Vec<(i128, i128)> = vec![(0, 1)];
+    for cycle in cycles {
+        let length = cycle.cycle_length as i128;
+        combinations = combinations
+            .iter()
+            .flat_map(|&(residue, modulus)| {
+                cycle.cycle_hits.iter().filter_map(move |&hit| {
+                    math::crt(&[(residue, modulus), (hit as i128 % length, length)])
+                })
+            })
+            .collect();
+    }
+    combinations
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let behind = (settled - residue).max(0);
+            residue + (behind + modulus - 1) / modulus * modulus
+        })
+        .min()
+        .map(|step| step as u128)
+        .ok_or(Error::NeverSynchronised)
+}
+
+pub fn part_two(network: &Network) -> Result<u128, Error> {
+    ghost_steps(network, None)
+}
+
+/// Walks every `..A` ghost until they all stand on `..Z` nodes at once, giving up
+/// if finding any ghost's cycle takes more than `max_steps` steps.
+pub fn ghost_steps(network: &Network, max_steps: Option<usize>) -> Result<u128, Error> {
+    let walker = Walker::for_part(network, Part::Two).max_steps(max_steps);
+    walker.ghost_steps(&walker.walk_starts()?)
+}
+
+/// Walks the network from the nodes matching a start pattern to the nodes
+/// matching a goal pattern.
+///
+/// The goal pattern is matched against every node once up front, so the walks
+/// themselves stay pure indexing.
+pub struct Walker<'a> {
+    network: &'a Network,
+    start: NodePattern,
+    goal: NodePattern,
+    is_goal: Vec<bool>,
+    max_steps: Option<usize>,
+}
+
+impl<'a> Walker<'a> {
+    pub fn new(network: &'a Network, start: NodePattern, goal: NodePattern) -> Self {
+        let graph = &network.graph;
+        let is_goal = graph.ids().map(|id| goal.matches(graph.name(id))).collect();
+        Self {
+            network,
+            start,
+            goal,
+            is_goal,
+            max_steps: None,
+        }
+    }
+
+    /// The puzzle's own walk for `part`.
+    pub fn for_part(network: &'a Network, part: Part) -> Self {
+        let (start, goal) = NodePattern::for_part(part);
+        Self::new(network, start, goal)
+    }
+
+    /// Gives up once a single walk has taken `max_steps` steps.
+    pub fn max_steps(mut self, max_steps: Option<usize>) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    pub fn is_goal(&self, id: NodeId) -> bool {
+        self.is_goal[id as usize]
+    }
+
+    /// Every node matching the start pattern, in definition order.
+    pub fn starts(&self) -> Result<Vec<NodeId>, Error> {
+        let graph = &self.network.graph;
+        let starts: Vec<NodeId> = match &self.start {
+            NodePattern::Exact(name) => graph.id(name).into_iter().collect(),
+            start => graph
+                .ids()
+                .filter(|&id| start.matches(graph.name(id)))
+                .collect(),
+        };
+        if starts.is_empty() {
+            return Err(Error::MissingStart(self.start.to_string()));
+        }
+        Ok(starts)
+    }
+
+    /// The starts of a walk, after checking that some node matches the goal
+    /// pattern, so a walk towards a missing goal fails before taking a step.
+    pub(crate) fn walk_starts(&self) -> Result<Vec<NodeId>, Error> {
+        let starts = self.starts()?;
+        if !self.is_goal.contains(&true) {
+            return Err(Error::MissingGoal(self.goal.to_string()));
+        }
+        Ok(starts)
+    }
+
+    /// Steps until every walk stands on a goal at the same time. A single start
+    /// walks straight to its first goal; several starts walk together as ghosts.
+    pub fn steps(&self) -> Result<u128, Error> {
+        match self.walk_starts()?.as_slice() {
+            &[start] => self
+                .walk_to_goal(start, |_, _, _| {})
+                .map(|steps| steps as u128),
+            starts => self.ghost_steps(starts),
+        }
+    }
+
+    /// Walks from `start` to the first goal, calling `visit` with the step number,
+    /// the instruction taken and the node arrived at after every step.
+    fn walk_to_goal(
+        &self,
+        start: NodeId,
+        mut visit: impl FnMut(usize, Instruction, NodeId),
+    ) -> Result<usize, Error> {
+        let Network {
+            instructions,
+            graph,
+        } = self.network;
+        let mut instruction_iter = Cyclic::new(instructions);
+        let mut current = start;
+        let mut visited: HashSet<(NodeId, usize)> = HashSet::new();
+        let mut steps: usize = 0;
+        while !self.is_goal(current) {
+            if !visited.insert((current, instruction_iter.position())) {
+                return Err(Error::Unreachable {
+                    start: graph.name(start).to_string(),
+                    goal: self.goal.to_string(),
+                });
+            }
+            if self.max_steps.is_some_and(|max_steps| steps >= max_steps) {
+                return Err(Error::StepBudgetExceeded(steps));
+            }
+            let direction = instruction_iter.next().ok_or(Error::EmptyInput)?;
+            current = graph.next(current, direction);
+            steps += 1;
+            visit(steps, direction, current);
+        }
+        Ok(steps)
+    }
+
+    /// Walks one ghost until it revisits a state, calling `visit` after every step
+    /// (including the one that closes the cycle).
+    fn find_cycle(
+        &self,
+        start: NodeId,
+        mut visit: impl FnMut(usize, Instruction, NodeId),
+    ) -> Result<GhostCycle, Error> {
+        let Network {
+            instructions,
+            graph,
+        } = self.network;
+        let mut instruction_iter = Cyclic::new(instructions);
+        // First step each (node, next instruction) state was seen at.
+        let mut seen: HashMap<(NodeId, usize), usize> = HashMap::new();
+        let mut hits: Vec<usize> = Vec::new();
+        let mut steps: usize = 0;
+        let mut current = start;
+        loop {
+            if self.max_steps.is_some_and(|max_steps| steps >= max_steps) {
+                return Err(Error::StepBudgetExceeded(steps));
+            }
+            let direction = instruction_iter.next().ok_or(Error::EmptyInput)?;
+            current = graph.next(current, direction);
+            steps += 1;
+            visit(steps, direction, current);
+            let state = (current, instruction_iter.position());
+            if let Some(&cycle_start) = seen.get(&state) {
+                let (prefix_hits, cycle_hits) =
+                    hits.into_iter().partition(|&hit| hit < cycle_start);
+                return Ok(GhostCycle {
+                    cycle_start,
+                    cycle_length: steps - cycle_start,
+                    prefix_hits,
+                    cycle_hits,
+                });
+            }
+            seen.insert(state, steps);
+            if self.is_goal(current) {
+                hits.push(steps);
+            }
+        }
+    }
+
+    fn ghost_steps(&self, starts: &[NodeId]) -> Result<u128, Error> {
+        let cycles: Vec<GhostCycle> = starts
+            .iter()
+            .map(|&start| {
+                let cycle = self.find_cycle(start, |_, _, _| {})?;
+                if cycle.prefix_hits.is_empty() && cycle.cycle_hits.is_empty() {
+                    return Err(Error::Unreachable {
+                        start: self.network.graph.name(start).to_string(),
+                        goal: self.goal.to_string(),
+                    });
+                }
+                Ok(cycle)
+            })
+            .collect::<Result<_, _>>()?;
+        first_common_hit(&cycles)
+    }
+}
+
+#[derive(Debug)]
+pub struct Network {
+    pub instructions: Instructions,
+    pub graph: Graph,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Answer = u128;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_one(network: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        part_one(network).map(|steps| steps as u128)
+    }
+}
+
+impl PartTwo for Day8 {
+    fn part_two(network: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        part_two(network)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_ONE: &str = r"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    pub(crate) const TEST_INPUT_TWO: &str = r"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    pub(crate) const LATE_ALIGNED_INPUT: &str = r"L
+
+11A = (11B, 11B)
+11B = (11C, 11C)
+11C = (11D, 11D)
+11D = (11E, 11E)
+11E = (11F, 11F)
+11F = (11Z, 11Z)
+11Z = (11G, 11G)
+11G = (11F, 11F)
+";
+
+    #[test]
+    fn test_part_one() {
+        let network = parse_input(TEST_INPUT_ONE).unwrap();
+        assert_eq!(part_one(&network), Ok(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let network = parse_input(TEST_INPUT_TWO).unwrap();
+        assert_eq!(part_two(&network), Ok(6));
+    }
+
+    #[test]
+    fn test_part_two_shared_prime_factors() {
+        let network = parse_input(
+            r"L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22D, 22D)
+22D = (22Z, 22Z)
+22Z = (22B, 22B)
+",
+        )
+        .unwrap();
+        assert_eq!(part_two(&network), Ok(4));
+    }
+
+    #[test]
+    fn test_part_two_unaligned_cycles() {
+        // 11A is on 11Z after one step and then every three steps (1, 4, 7..), while
+        // 22A is on a goal at steps 2 and 3 and then every four steps (2, 3, 6, 7..).
+        let network = parse_input(
+            r"L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (2QZ, 2QZ)
+2QZ = (22C, 22C)
+22C = (22D, 22D)
+22D = (22Z, 22Z)
+",
+        )
+        .unwrap();
+        assert_eq!(part_two(&network), Ok(7));
+    }
+
+    #[test]
+    fn test_part_two_late_aligned_cycle() {
+        // The cycle 11F, 11Z, 11G starts at step 5 and is three steps long, but its
+        // only goal hit is at step 6, so the lcm of 3 would be too early.
+        let network = parse_input(LATE_ALIGNED_INPUT).unwrap();
+        assert_eq!(part_two(&network), Ok(6));
+    }
+
+    #[test]
+    fn test_first_common_hit() {
+        let cycles = [
+            GhostCycle {
+                cycle_start: 3,
+                cycle_length: 5,
+                prefix_hits: vec![1],
+                cycle_hits: vec![4],
+            },
+            GhostCycle {
+                cycle_start: 1,
+                cycle_length: 3,
+                prefix_hits: vec![],
+                cycle_hits: vec![1],
+            },
+        ];
+        // Ghost one: 1, 4, 9, 14, 19..; ghost two: 1, 4, 7, 10, 13, 16, 19..
+        assert_eq!(first_common_hit(&cycles), Ok(1));
+        let cycles = [
+            GhostCycle {
+                prefix_hits: vec![],
+                ..cycles[0].clone()
+            },
+            cycles[1].clone(),
+        ];
+        assert_eq!(first_common_hit(&cycles), Ok(4));
+        let never = [
+            GhostCycle {
+                cycle_start: 1,
+                cycle_length: 2,
+                prefix_hits: vec![],
+                cycle_hits: vec![1],
+            },
+            GhostCycle {
+                cycle_start: 1,
+                cycle_length: 2,
+                prefix_hits: vec![],
+                cycle_hits: vec![2],
+            },
+        ];
+        assert_eq!(first_common_hit(&never), Err(Error::NeverSynchronised));
+        assert_eq!(first_common_hit(&[]), Ok(0));
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let network = parse_input(
+            r"LR
+
+AAA = (BBB, BBB)
+BBB = (AAA, CCC)
+CCC = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)
+11A = (11B, 11B)
+11B = (11A, 11A)
+22A = (22Z, 22Z)
+22Z = (22A, 22A)
+",
+        )
+        .unwrap();
+        let unreachable = |start: &str, goal: &str| Error::Unreachable {
+            start: start.to_string(),
+            goal: goal.to_string(),
+        };
+        assert_eq!(part_one(&network), Err(unreachable("AAA", "ZZZ")));
+        // AAA is itself a ghost start, and the first one that never reaches a goal.
+        assert_eq!(part_two(&network), Err(unreachable("AAA", "*Z")));
+    }
+
+    #[test]
+    fn test_step_budget() {
+        let network = parse_input(TEST_INPUT_TWO).unwrap();
+        assert_eq!(
+            ghost_steps(&network, Some(3)),
+            Err(Error::StepBudgetExceeded(3))
+        );
+        assert_eq!(ghost_steps(&network, Some(100)), Ok(6));
+
+        let network = parse_input(TEST_INPUT_ONE).unwrap();
+        assert_eq!(
+            steps_to_goal(&network, Some(1)),
+            Err(Error::StepBudgetExceeded(1))
+        );
+        assert_eq!(steps_to_goal(&network, Some(2)), Ok(2));
+    }
+
+    #[test]
+    fn test_walker_patterns() {
+        let network = parse_input(TEST_INPUT_TWO).unwrap();
+        let walker = |start: &str, goal: &str| {
+            Walker::new(&network, start.parse().unwrap(), goal.parse().unwrap())
+        };
+        assert_eq!(walker("22A", "22Z").steps(), Ok(3));
+        assert_eq!(walker("22A", "2?B").steps(), Ok(1));
+        assert_eq!(walker("1*A", "*Z").steps(), Ok(2));
+        assert_eq!(walker("*A", "*Z").steps(), Ok(6));
+        assert_eq!(walker("1?A", "*1B").steps(), Ok(1));
+        assert_eq!(
+            walker("*A", "ZZZ").steps(),
+            Err(Error::MissingGoal("ZZZ".to_string()))
+        );
+        assert_eq!(
+            walker("11A", "ZZZ").steps(),
+            Err(Error::MissingGoal("ZZZ".to_string()))
+        );
+        assert_eq!(
+            walker("3*", "*Z").steps(),
+            Err(Error::MissingStart("3*".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse_input("").unwrap_err(), Error::EmptyInput);
+        assert_eq!(
+            parse_input("LXR\n\nAAA = (AAA, AAA)").unwrap_err(),
+            Error::Parse(ParseError::new(1, 2, "expected `L` or `R`, found `X`"))
+        );
+        let network = parse_input(TEST_INPUT_TWO).unwrap();
+        assert_eq!(
+            part_one(&network),
+            Err(Error::MissingStart("AAA".to_string()))
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use aoc_common::ParseError;
+
+use crate::{Error, Graph, Instructions, Network};
+
+/// A node name and the 1-based column it starts at.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Token {
+    pub text: String,
+    pub column: usize,
+}
+
+/// One `NAME = (LEFT, RIGHT)` line of the network.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NodeEntry {
+    pub line: usize,
+    pub name: Token,
+    pub left: Token,
+    pub right: Token,
+}
+
+struct Cursor<'a> {
+    line: &'a str,
+    line_number: usize,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(line: &'a str, line_number: usize) -> Self {
+        Self {
+            line,
+            line_number,
+            position: 0,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        let found = match self.rest().chars().next() {
+            Some(c) => format!("`{}`", c),
+            None => "the end of the line".to_string(),
+        };
+        ParseError::new(
+            self.line_number,
+            self.position + 1,
+            format!("expected {}, found {}", expected, found),
+        )
+    }
+
+    fn symbol(&mut self, symbol: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if !self.rest().starts_with(symbol) {
+            return Err(self.error(&format!("`{}`", symbol)));
+        }
+        self.position += symbol.len_utf8();
+        Ok(())
+    }
+
+    fn name(&mut self) -> Result<Token, ParseError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error("a node name"));
+        }
+        let token = Token {
+            text: rest[..length].to_string(),
+            column: self.position + 1,
+        };
+        self.position += length;
+        Ok(token)
+    }
+
+    fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("the end of the line"))
+        }
+    }
+}
+
+/// Parses a `NAME = (LEFT, RIGHT)` line; names are any run of letters, digits and `_`.
+pub fn parse_node(line: &str, line_number: usize) -> Result<NodeEntry, ParseError> {
+    let mut cursor = Cursor::new(line, line_number);
+    let name = cursor.name()?;
+    cursor.symbol('=')?;
+    cursor.symbol('(')?;
+    let left = cursor.name()?;
+    cursor.symbol(',')?;
+    let right = cursor.name()?;
+    cursor.symbol(')')?;
+    cursor.end()?;
+    Ok(NodeEntry {
+        line: line_number,
+        name,
+        left,
+        right,
+    })
+}
+
+fn parse_instructions(line: &str) -> Result<Instructions, ParseError> {
+    line.char_indices()
+        .map(|(index, c)| {
+            c.to_string().parse().map_err(|_| {
+                ParseError::new(1, index + 1, format!("expected `L` or `R`, found `{}`", c))
+            })
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Network, Error> {
+    let mut lines = input.lines();
+    let raw_instructions = lines.next().ok_or(Error::EmptyInput)?.trim_end();
+    if raw_instructions.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    let instructions = parse_instructions(raw_instructions)?;
+    let entries: Vec<NodeEntry> = lines
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_node(line, index + 2))
+        .collect::<Result<_, _>>()?;
+    Ok(Network {
+        instructions,
+        graph: Graph::build(&entries)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(text: &str, column: usize) -> Token {
+        Token {
+            text: text.to_string(),
+            column,
+        }
+    }
+
+    #[test]
+    fn test_parse_node() {
+        assert_eq!(
+            parse_node("start_1 =(LEFT,  r)", 4),
+            Ok(NodeEntry {
+                line: 4,
+                name: token("start_1", 1),
+                left: token("LEFT", 11),
+                right: token("r", 18),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_node_errors() {
+        for (line, column, message) in [
+            ("", 1, "expected a node name, found the end of the line"),
+            ("AAA (BBB, CCC)", 5, "expected `=`, found `(`"),
+            ("AAA = BBB, CCC)", 7, "expected `(`, found `B`"),
+            ("AAA = (BBB CCC)", 12, "expected `,`, found `C`"),
+            ("AAA = (BBB, )", 13, "expected a node name, found `)`"),
+            (
+                "AAA = (BBB, CCC",
+                16,
+                "expected `)`, found the end of the line",
+            ),
+            (
+                "AAA = (BBB, CCC) x",
+                18,
+                "expected the end of the line, found `x`",
+            ),
+        ] {
+            assert_eq!(
+                parse_node(line, 3),
+                Err(ParseError::new(3, column, message)),
+                "{}",
+                line
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_input_reports_line_numbers() {
+        let input = "LR\n\nAAA = (BBB, BBB)\n\nBBB = (AAA, ZZZ\nZZZ = (ZZZ, ZZZ)\n";
+        assert_eq!(
+            parse_input(input).unwrap_err(),
+            Error::Parse(ParseError::new(
+                5,
+                16,
+                "expected `)`, found the end of the line"
+            ))
+        );
+    }
+}
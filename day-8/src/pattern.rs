@@ -0,0 +1,146 @@
+use std::{fmt, str::FromStr};
+
+use aoc_common::Part;
+
+/// Selects nodes by name: `NAME` exactly, `PRE*` by prefix, `*SUF` by suffix, or
+/// any other glob where `*` matches a run of characters and `?` a single one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NodePattern {
+    Exact(String),
+    Prefix(String),
+    Suffix(String),
+    Glob(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePatternError;
+
+impl FromStr for NodePattern {
+    type Err = ParsePatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePatternError);
+        }
+        let is_wildcard = |c: char| c == '*' || c == '?';
+        if !s.contains(is_wildcard) {
+            return Ok(NodePattern::Exact(s.to_string()));
+        }
+        if let Some(prefix) = s
+            .strip_suffix('*')
+            .filter(|rest| !rest.contains(is_wildcard))
+        {
+            return Ok(NodePattern::Prefix(prefix.to_string()));
+        }
+        if let Some(suffix) = s
+            .strip_prefix('*')
+            .filter(|rest| !rest.contains(is_wildcard))
+        {
+            return Ok(NodePattern::Suffix(suffix.to_string()));
+        }
+        Ok(NodePattern::Glob(s.to_string()))
+    }
+}
+
+impl fmt::Display for NodePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodePattern::Exact(name) => write!(f, "{}", name),
+            NodePattern::Prefix(prefix) => write!(f, "{}*", prefix),
+            NodePattern::Suffix(suffix) => write!(f, "*{}", suffix),
+            NodePattern::Glob(pattern) => write!(f, "{}", pattern),
+        }
+    }
+}
+
+impl NodePattern {
+    /// The start and goal patterns of the puzzle's own walk for `part`.
+    pub fn for_part(part: Part) -> (Self, Self) {
+        match part {
+            Part::One => (
+                NodePattern::Exact("AAA".to_string()),
+                NodePattern::Exact("ZZZ".to_string()),
+            ),
+            Part::Two => (
+                NodePattern::Suffix("A".to_string()),
+                NodePattern::Suffix("Z".to_string()),
+            ),
+        }
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            NodePattern::Exact(exact) => name == exact,
+            NodePattern::Prefix(prefix) => name.starts_with(prefix.as_str()),
+            NodePattern::Suffix(suffix) => name.ends_with(suffix.as_str()),
+            NodePattern::Glob(pattern) => glob_matches(pattern.as_bytes(), name.as_bytes()),
+        }
+    }
+}
+
+/// Greedy glob matching that backtracks only to the most recent `*`, which is
+/// enough because a later `*` can absorb anything an earlier one could.
+fn glob_matches(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(s: &str) -> NodePattern {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(pattern("AAA"), NodePattern::Exact("AAA".to_string()));
+        assert_eq!(pattern("1*"), NodePattern::Prefix("1".to_string()));
+        assert_eq!(pattern("*Z"), NodePattern::Suffix("Z".to_string()));
+        assert_eq!(pattern("?1*Z"), NodePattern::Glob("?1*Z".to_string()));
+        assert_eq!("".parse::<NodePattern>(), Err(ParsePatternError));
+        for raw in ["AAA", "1*", "*Z", "?1*Z"] {
+            assert_eq!(pattern(raw).to_string(), raw);
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        for (raw, name, expected) in [
+            ("AAA", "AAA", true),
+            ("AAA", "AAAA", false),
+            ("11*", "11Z", true),
+            ("*Z", "ZZA", false),
+            ("?1?", "212", true),
+            ("?1?", "2122", false),
+            ("*1*Z", "X1YYZ", true),
+            ("*1*Z", "X1YYZA", false),
+            ("A*B*C", "AxxBxxBxxC", true),
+            ("*", "", true),
+        ] {
+            assert_eq!(pattern(raw).matches(name), expected, "{} {}", raw, name);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{Error, Instruction, Walker};
+
+/// One step of a walk: the instruction taken and the node it led to.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TraceStep {
+    /// The node this walk started from, which tells the ghosts apart.
+    pub start: String,
+    pub step: usize,
+    pub instruction: Instruction,
+    pub node: String,
+    pub goal: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TraceFormat {
+    JsonLines,
+    Csv,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseTraceFormatError;
+
+impl FromStr for TraceFormat {
+    type Err = ParseTraceFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jsonl" => Ok(TraceFormat::JsonLines),
+            "csv" => Ok(TraceFormat::Csv),
+            _ => Err(ParseTraceFormatError),
+        }
+    }
+}
+
+/// Records every step of `walker`'s walks.
+///
+/// A single start is traced until it reaches a goal. Several starts walk as ghosts,
+/// which never stop on their own, so each one is traced until it first revisits a
+/// (node, instruction index) state; the last step recorded for a ghost is the one
+/// that closes its cycle. With `goals_only` only the steps that land on a goal are
+/// kept.
+pub fn trace(walker: &Walker, goals_only: bool) -> Result<Vec<TraceStep>, Error> {
+    let graph = &walker.network.graph;
+    let starts = walker.walk_starts()?;
+    let mut steps = Vec::new();
+    for &start in &starts {
+        let name = graph.name(start);
+        let mut record = |step, instruction, node| {
+            let goal = walker.is_goal(node);
+            if goal || !goals_only {
+                steps.push(TraceStep {
+                    start: name.to_string(),
+                    step,
+                    instruction,
+                    node: graph.name(node).to_string(),
+                    goal,
+                });
+            }
+        };
+        if starts.len() == 1 {
+            walker.walk_to_goal(start, &mut record)?;
+        } else {
+            walker.find_cycle(start, &mut record)?;
+        }
+    }
+    Ok(steps)
+}
+
+/// Renders a trace as JSON Lines or CSV with a header row. Node names are only
+/// ever letters, digits and `_`, so neither format needs escaping.
+pub fn render_trace(steps: &[TraceStep], format: TraceFormat) -> String {
+    let mut output = String::new();
+    if format == TraceFormat::Csv {
+        output.push_str("start,step,instruction,node,goal\n");
+    }
+    for step in steps {
+        match format {
+            TraceFormat::JsonLines => writeln!(
+                output,
+                "{{\"start\":\"{}\",\"step\":{},\"instruction\":\"{}\",\"node\":\"{}\",\"goal\":{}}}",
+                step.start, step.step, step.instruction, step.node, step.goal
+            ),
+            TraceFormat::Csv => writeln!(
+                output,
+                "{},{},{},{},{}",
+                step.start, step.step, step.instruction, step.node, step.goal
+            ),
+        }
+        .unwrap();
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Part;
+
+    use crate::{parse_input, tests::TEST_INPUT_TWO};
+
+    #[test]
+    fn test_trace_part_one() {
+        let network =
+            parse_input("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        let steps = trace(&Walker::for_part(&network, Part::One), false).unwrap();
+        let expected = "start,step,instruction,node,goal
+AAA,1,L,BBB,false
+AAA,2,L,AAA,false
+AAA,3,R,BBB,false
+AAA,4,L,AAA,false
+AAA,5,L,BBB,false
+AAA,6,R,ZZZ,true
+";
+        assert_eq!(render_trace(&steps, TraceFormat::Csv), expected);
+    }
+
+    #[test]
+    fn test_trace_goals_only() {
+        let network = parse_input(TEST_INPUT_TWO).unwrap();
+        let steps = trace(&Walker::for_part(&network, Part::Two), true).unwrap();
+        let hits: Vec<(&str, usize)> = steps
+            .iter()
+            .map(|step| (step.start.as_str(), step.step))
+            .collect();
+        // Each ghost is traced until its walk repeats: 11A at step 3, 22A at step 7.
+        assert_eq!(hits, vec![("11A", 2), ("22A", 3), ("22A", 6)]);
+        assert_eq!(
+            render_trace(&steps[..1], TraceFormat::JsonLines),
+            "{\"start\":\"11A\",\"step\":2,\"instruction\":\"R\",\"node\":\"11Z\",\"goal\":true}\n"
+        );
+    }
+
+    #[test]
+    fn test_trace_respects_step_budget() {
+        let network = parse_input(TEST_INPUT_TWO).unwrap();
+        assert_eq!(
+            trace(
+                &Walker::for_part(&network, Part::Two).max_steps(Some(3)),
+                false
+            ),
+            Err(Error::StepBudgetExceeded(3))
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::value::Value;
+
+const BASE: u64 = 1 << 32;
+/// The largest power of ten that fits in a limb, for decimal conversion.
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+/// An arbitrary-precision signed integer, stored as a sign and little-endian
+/// base-2^32 limbs with no trailing zero limbs. Zero has no limbs and is never
+/// negative.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl BigInt {
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Truncating division by a small positive number, returning the quotient and
+    /// the remainder of the magnitude.
+    pub fn div_small(&self, divisor: u32) -> (BigInt, u32) {
+        let (quotient, remainder) = div_small_magnitude(&self.magnitude, divisor);
+        (BigInt::new(self.negative, quotient), remainder)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(
+                self.negative,
+                add_magnitudes(&self.magnitude, &other.magnitude),
+            );
+        }
+        match compare_magnitudes(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::new(
+                other.negative,
+                sub_magnitudes(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::new(
+                self.negative,
+                sub_magnitudes(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        let mut product = vec![0u32; self.magnitude.len() + other.magnitude.len()];
+        for (i, &a) in self.magnitude.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.magnitude.iter().enumerate() {
+                let current = product[i + j] as u64 + a as u64 * b as u64 + carry;
+                product[i + j] = current as u32;
+                carry = current >> 32;
+            }
+            product[i + other.magnitude.len()] = carry as u32;
+        }
+        BigInt::new(self.negative != other.negative, product)
+    }
+}
+
+fn compare_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let current = limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        sum.push(current as u32);
+        carry = current >> 32;
+    }
+    sum.push(carry as u32);
+    sum
+}
+
+/// `a - b` for `a >= b`.
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0u64;
+    for (i, &limb) in a.iter().enumerate() {
+        let subtrahend = b.get(i).copied().unwrap_or(0) as u64 + borrow;
+        if (limb as u64) < subtrahend {
+            difference.push((limb as u64 + BASE - subtrahend) as u32);
+            borrow = 1;
+        } else {
+            difference.push((limb as u64 - subtrahend) as u32);
+            borrow = 0;
+        }
+    }
+    difference
+}
+
+fn div_small_magnitude(magnitude: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; magnitude.len()];
+    let mut remainder = 0u64;
+    for (i, &limb) in magnitude.iter().enumerate().rev() {
+        let current = (remainder << 32) | limb as u64;
+        quotient[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    (quotient, remainder as u32)
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut magnitude = Vec::new();
+        let mut rest = value.unsigned_abs();
+        while rest > 0 {
+            magnitude.push(rest as u32);
+            rest >>= 32;
+        }
+        BigInt::new(value < 0, magnitude)
+    }
+}
+
+impl From<isize> for BigInt {
+    fn from(value: isize) -> Self {
+        BigInt::from(value as i128)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+        let mut magnitude: Vec<u32> = Vec::new();
+        // Take the digits nine at a time: multiply by 10^n and add the chunk.
+        let first = digits.len() % DECIMAL_CHUNK_DIGITS;
+        let chunks = std::iter::once(&digits[..first]).chain(
+            digits.as_bytes()[first..]
+                .chunks(DECIMAL_CHUNK_DIGITS)
+                .map(|chunk| std::str::from_utf8(chunk).unwrap()),
+        );
+        for chunk in chunks.filter(|chunk| !chunk.is_empty()) {
+            let mut carry = chunk.parse::<u64>().unwrap();
+            let scale = 10u64.pow(chunk.len() as u32);
+            for limb in magnitude.iter_mut() {
+                let current = *limb as u64 * scale + carry;
+                *limb = current as u32;
+                carry = current >> 32;
+            }
+            if carry > 0 {
+                magnitude.push(carry as u32);
+            }
+        }
+        Ok(BigInt::new(negative, magnitude))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.magnitude.is_empty() {
+            return write!(f, "0");
+        }
+        let mut chunks = Vec::new();
+        let mut rest = self.magnitude.clone();
+        while !rest.is_empty() {
+            let (quotient, remainder) = div_small_magnitude(&rest, DECIMAL_CHUNK);
+            chunks.push(remainder);
+            rest = BigInt::new(false, quotient).magnitude;
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.pop().unwrap())?;
+        for chunk in chunks.iter().rev() {
+            write!(f, "{:09}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl Value for BigInt {
+    fn from_isize(value: isize) -> Self {
+        BigInt::from(value)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn div_exact(&self, divisor: u32) -> Self {
+        self.div_small(divisor).0
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &limb| acc << 32 | limb as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for s in [
+            "0",
+            "-1",
+            "4294967296",
+            "-123456789012345678901234567890123456789",
+        ] {
+            assert_eq!(big(s).to_string(), s);
+        }
+        assert_eq!(big("-0"), BigInt::default());
+        assert_eq!(big("+0042").to_string(), "42");
+        assert_eq!("".parse::<BigInt>(), Err(ParseBigIntError));
+        assert_eq!("1-2".parse::<BigInt>(), Err(ParseBigIntError));
+    }
+
+    #[test]
+    fn test_arithmetic_matches_i128() {
+        let values: [i128; 7] = [
+            0,
+            1,
+            -7,
+            u32::MAX as i128,
+            -(u64::MAX as i128),
+            123_456_789_012_345,
+            -98_765_432_109_876_543_210,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (BigInt::from(a), BigInt::from(b));
+                assert_eq!(&x + &y, BigInt::from(a + b), "{} + {}", a, b);
+                assert_eq!(&x - &y, BigInt::from(a - b), "{} - {}", a, b);
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!(&x * &y, BigInt::from(product), "{} * {}", a, b);
+                }
+            }
+            assert_eq!(BigInt::from(a).div_small(7).0, BigInt::from(a / 7));
+            assert_eq!(BigInt::from(a).to_i128(), Some(a));
+        }
+    }
+
+    #[test]
+    fn test_beyond_i128() {
+        let huge = &big("170141183460469231731687303715884105727") + &BigInt::from(1isize);
+        assert_eq!(huge.to_string(), "170141183460469231731687303715884105728");
+        assert_eq!(huge.to_i128(), None);
+        assert_eq!((-&huge).to_i128(), Some(i128::MIN));
+        assert_eq!(
+            (&huge * &huge).div_small(2).0.to_string(),
+            "14474011154664524427946373126085988481658748083205070504932198000989141204992"
+        );
+    }
+}
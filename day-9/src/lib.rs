@@ -0,0 +1,365 @@
+use std::{
+    error, fmt,
+    io::{self, BufRead},
+};
+
+use aoc_common::{tokens, ParseError, PartTwo, Solution};
+
+mod bigint;
+mod newton;
+mod pyramid;
+mod rational;
+mod value;
+
+pub use bigint::{BigInt, ParseBigIntError};
+pub use newton::{format_polynomial, DifferenceTable};
+pub use pyramid::{render_pyramid, ParseStyleError, Style};
+pub use rational::Rational;
+pub use value::Value;
+
+/// The integer type the puzzle is solved in: `isize` with overflow reported as an
+/// error, or [`BigInt`] with the `bigint` feature.
+#[cfg(not(feature = "bigint"))]
+pub type Integer = isize;
+#[cfg(feature = "bigint")]
+pub type Integer = BigInt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    EmptyInput,
+    Parse(ParseError),
+    EmptyHistory { line: usize },
+    Diverges { line: usize },
+    Overflow { line: usize },
+    Read { line: usize, kind: io::ErrorKind },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyInput => write!(f, "the input has no histories"),
+            Error::Parse(error) => write!(f, "invalid history: {}", error),
+            Error::EmptyHistory { line } => write!(f, "line {}: the history is empty", line),
+            Error::Diverges { line } => write!(
+                f,
+                "line {}: the differences never reach a row of zeros",
+                line
+            ),
+            #[cfg(not(feature = "bigint"))]
+            Error::Overflow { line } => write!(
+                f,
+                "line {}: the prediction overflows `isize`; build with the `bigint` feature",
+                line
+            ),
+            // Values cannot overflow here, only the step offset, which stays an `isize`.
+            #[cfg(feature = "bigint")]
+            Error::Overflow { line } => {
+                write!(f, "line {}: the step offset overflows `isize`", line)
+            }
+            Error::Read { line, kind } => {
+                write!(f, "line {}: could not read input: {}", line, kind)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+/// The differences between consecutive values: one level of the difference pyramid.
+/// `None` if a difference overflows.
+pub fn get_changes<N: Value>(input: &[N]) -> Option<Vec<N>> {
+    input
+        .windows(2)
+        .map(|win| win[1].checked_sub(&win[0]))
+        .collect()
+}
+
+/// Parses one line of whitespace-separated integers; `line` is 1-based.
+pub fn parse_history(text: &str, line: usize) -> Result<Vec<Integer>, Error> {
+    if text.trim().is_empty() {
+        return Err(Error::EmptyHistory { line });
+    }
+    tokens(text)
+        .map(|(column, token)| {
+            token.parse().map_err(|_| {
+                Error::from(ParseError::new(
+                    line,
+                    column,
+                    format!("expected an integer, found `{}`", token),
+                ))
+            })
+        })
+        .collect()
+}
+
+pub fn parse_histories(input: &str) -> Result<Vec<Vec<Integer>>, Error> {
+    // Trailing blank lines are only the end of the file, not empty histories.
+    let histories: Vec<Vec<Integer>> = input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_history(line, index + 1))
+        .collect::<Result<_, _>>()?;
+    if histories.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    Ok(histories)
+}
+
+/// The difference table of the history on `line`, which must reach a row of
+/// zeros for its predictions to mean anything.
+pub fn difference_table(
+    history: &[Integer],
+    line: usize,
+) -> Result<DifferenceTable<Integer>, Error> {
+    let table = DifferenceTable::new(history).ok_or(Error::Overflow { line })?;
+    if !table.converges() {
+        return Err(Error::Diverges { line });
+    }
+    Ok(table)
+}
+
+/// Sums `predict` over every history, reporting the line of the first failure.
+fn sum_predictions(
+    histories: &[Vec<Integer>],
+    predict: impl Fn(&DifferenceTable<Integer>) -> Option<Integer>,
+) -> Result<Integer, Error> {
+    histories
+        .iter()
+        .enumerate()
+        .try_fold(Integer::from_isize(0), |sum, (index, history)| {
+            let line = index + 1;
+            let table = difference_table(history, line)?;
+            predict(&table)
+                .and_then(|prediction| Value::checked_add(&sum, &prediction))
+                .ok_or(Error::Overflow { line })
+        })
+}
+
+pub fn part_one(histories: &[Vec<Integer>]) -> Result<Integer, Error> {
+    sum_predictions(histories, |table| table.forward(1))
+}
+
+pub fn part_two(histories: &[Vec<Integer>]) -> Result<Integer, Error> {
+    sum_predictions(histories, |table| table.backward(1))
+}
+
+/// Both answers, summed in a single pass.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Predictions {
+    pub next: Integer,
+    pub previous: Integer,
+}
+
+/// Solves both parts while reading one line at a time, so memory use is bounded
+/// by the longest line rather than the whole input.
+pub fn solve_stream(mut reader: impl BufRead) -> Result<Predictions, Error> {
+    let mut buffer = String::new();
+    let mut predictions: Option<Predictions> = None;
+    // The first of the blank lines since the last history; only an error if
+    // another history follows.
+    let mut blank_line: Option<usize> = None;
+    for line in 1.. {
+        buffer.clear();
+        match reader.read_line(&mut buffer) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(error) => {
+                return Err(Error::Read {
+                    line,
+                    kind: error.kind(),
+                })
+            }
+        }
+        if buffer.trim().is_empty() {
+            blank_line.get_or_insert(line);
+            continue;
+        }
+        if let Some(line) = blank_line {
+            return Err(Error::EmptyHistory { line });
+        }
+        let table = difference_table(&parse_history(&buffer, line)?, line)?;
+        let (next, previous) = table
+            .forward(1)
+            .zip(table.backward(1))
+            .ok_or(Error::Overflow { line })?;
+        predictions = Some(match predictions {
+            None => Predictions { next, previous },
+            Some(sums) => Predictions {
+                next: Value::checked_add(&sums.next, &next).ok_or(Error::Overflow { line })?,
+                previous: Value::checked_add(&sums.previous, &previous)
+                    .ok_or(Error::Overflow { line })?,
+            },
+        });
+    }
+    predictions.ok_or(Error::EmptyInput)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<Integer>>;
+    type Answer = Integer;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_histories(input)
+    }
+
+    fn part_one(histories: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        part_one(histories)
+    }
+}
+
+impl PartTwo for Day9 {
+    fn part_two(histories: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        part_two(histories)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    const TEST_INPUT: &str = r"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn test_get_changes() {
+        let input: Vec<isize> = vec![0, 3, 6, 9, 12, 15];
+        let expected: Vec<isize> = vec![3, 3, 3, 3, 3];
+        let output = get_changes(&input);
+        assert_eq!(Some(expected), output);
+    }
+
+    #[test]
+    fn test_part_one() {
+        // Arrange
+
+        // Act
+        let result = part_one(&parse_histories(TEST_INPUT).unwrap());
+
+        // Assert
+        assert_eq!(result, Ok(Integer::from(114isize)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse_histories(TEST_INPUT).unwrap());
+        assert_eq!(result, Ok(Integer::from(2isize)));
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    #[test]
+    fn test_overflow_reports_line() {
+        let input = format!(
+            "1 2 3\n{} {} {}\n",
+            isize::MAX - 2,
+            isize::MAX - 1,
+            isize::MAX
+        );
+        let histories = parse_histories(&input).unwrap();
+        assert_eq!(part_one(&histories), Err(Error::Overflow { line: 2 }));
+        assert_eq!(part_two(&histories), Ok(isize::MAX - 3));
+        assert_eq!(
+            Error::Overflow { line: 2 }.to_string(),
+            "line 2: the prediction overflows `isize`; build with the `bigint` feature"
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint_answers() {
+        let input = format!(
+            "1 2 3\n{} {} {}\n",
+            isize::MAX - 2,
+            isize::MAX - 1,
+            isize::MAX
+        );
+        let histories = parse_histories(&input).unwrap();
+        assert_eq!(
+            part_one(&histories).unwrap().to_string(),
+            "9223372036854775812"
+        );
+        assert_eq!(
+            Error::Overflow { line: 2 }.to_string(),
+            "line 2: the step offset overflows `isize`"
+        );
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let histories = parse_histories("0 1 2\n1 2 4\n").unwrap();
+        assert_eq!(part_one(&histories), Err(Error::Diverges { line: 2 }));
+        let histories = parse_histories("0 0 0\n7\n").unwrap();
+        assert_eq!(part_two(&histories), Err(Error::Diverges { line: 2 }));
+        let histories = parse_histories("0\n3 3\n").unwrap();
+        assert_eq!(part_one(&histories), Ok(Integer::from(3isize)));
+        assert_eq!(
+            parse_histories("1 2 3\n\n4 5 6\n\n"),
+            Err(Error::EmptyHistory { line: 2 })
+        );
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        assert_eq!(
+            solve_stream(TEST_INPUT.as_bytes()),
+            Ok(Predictions {
+                next: Integer::from(114isize),
+                previous: Integer::from(2isize),
+            })
+        );
+        assert_eq!(solve_stream("\n\n".as_bytes()), Err(Error::EmptyInput));
+        assert_eq!(
+            solve_stream("1 2 3\n\n\n4 5 6".as_bytes()),
+            Err(Error::EmptyHistory { line: 2 })
+        );
+        assert_eq!(
+            solve_stream("1 2 3\n1 x".as_bytes()),
+            Err(Error::Parse(ParseError::new(
+                2,
+                3,
+                "expected an integer, found `x`"
+            )))
+        );
+        assert_eq!(
+            solve_stream(&[b'1', b' ', 0xff, b'\n'][..]),
+            Err(Error::Read {
+                line: 1,
+                kind: io::ErrorKind::InvalidData
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_histories(""), Err(Error::EmptyInput));
+        assert_eq!(
+            parse_histories("1 2 3\n4 five 6"),
+            Err(Error::Parse(ParseError::new(
+                2,
+                3,
+                "expected an integer, found `five`"
+            )))
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{rational::Rational, value::Value};
+
+/// The leading diagonal of a history's forward-difference table: entry `k` is the
+/// `k`-th difference at the first value.
+///
+/// By Newton's forward-difference formula these are the coefficients of the
+/// history's interpolating polynomial in the binomial basis, so the history can
+/// be extended any number of steps in either direction without rebuilding the
+/// table.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DifferenceTable<N = isize> {
+    diagonal: Vec<N>,
+}
+
+impl<N: Value> DifferenceTable<N> {
+    /// Builds the table in a single buffer, replacing each level of differences
+    /// with the next one from the back. `None` if a difference overflows.
+    pub fn new(history: &[N]) -> Option<Self> {
+        let mut diagonal = history.to_vec();
+        for level in 1..diagonal.len() {
+            for index in (level..diagonal.len()).rev() {
+                diagonal[index] = diagonal[index].checked_sub(&diagonal[index - 1])?;
+            }
+        }
+        Some(Self { diagonal })
+    }
+
+    /// The number of values the table was built from.
+    pub fn len(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagonal.is_empty()
+    }
+
+    /// True if repeatedly taking differences reaches a row of zeros. The last row
+    /// holds the single entry at the end of the diagonal, so that entry decides it.
+    pub fn converges(&self) -> bool {
+        self.diagonal.last().is_some_and(Value::is_zero)
+    }
+
+    /// Degree of the interpolating polynomial: the deepest non-zero difference.
+    pub fn degree(&self) -> usize {
+        self.diagonal
+            .iter()
+            .rposition(|difference| !difference.is_zero())
+            .unwrap_or(0)
+    }
+
+    /// The interpolating polynomial at `x`, where the history's values sit at
+    /// `x = 0, 1, ..., len - 1`, or `None` if the arithmetic overflows.
+    pub fn value_at(&self, x: isize) -> Option<N> {
+        let mut binomial = N::from_isize(1);
+        let mut value = N::from_isize(0);
+        for (k, difference) in self.diagonal.iter().take(self.degree() + 1).enumerate() {
+            if k > 0 {
+                // C(x, k) = C(x, k - 1) * (x - k + 1) / k, which divides exactly.
+                let factor = x.checked_sub(k as isize - 1)?;
+                binomial = binomial
+                    .checked_mul(&N::from_isize(factor))?
+                    .div_exact(k as u32);
+            }
+            value = value.checked_add(&difference.checked_mul(&binomial)?)?;
+        }
+        Some(value)
+    }
+
+    /// The value `steps` places after the last one.
+    pub fn forward(&self, steps: usize) -> Option<N> {
+        let x = (self.len() as isize - 1).checked_add(isize::try_from(steps).ok()?)?;
+        self.value_at(x)
+    }
+
+    /// The value `steps` places before the first one.
+    pub fn backward(&self, steps: usize) -> Option<N> {
+        self.value_at(isize::try_from(steps).ok()?.checked_neg()?)
+    }
+
+    /// Coefficients of the interpolating polynomial in ascending powers of `x`,
+    /// with `x = 0` at the first value, or `None` if they overflow an `i128`.
+    pub fn coefficients(&self) -> Option<Vec<Rational>> {
+        let degree = self.degree();
+        let mut coefficients = vec![Rational::ZERO; degree + 1];
+        // Coefficients of C(x, k), starting from C(x, 0) = 1.
+        let mut basis = vec![Rational::from(1)];
+        for (k, difference) in self.diagonal.iter().take(degree + 1).enumerate() {
+            if k > 0 {
+                // C(x, k) = C(x, k - 1) * (x - (k - 1)) / k
+                let shift = Rational::new(-(k as i128 - 1), k as i128);
+                let scale = Rational::new(1, k as i128);
+                let mut next = vec![Rational::ZERO; k + 1];
+                for (power, &coefficient) in basis.iter().enumerate() {
+                    next[power] = next[power].checked_add(coefficient.checked_mul(shift)?)?;
+                    next[power + 1] =
+                        next[power + 1].checked_add(coefficient.checked_mul(scale)?)?;
+                }
+                basis = next;
+            }
+            let difference = Rational::from(difference.to_i128()?);
+            for (coefficient, &term) in coefficients.iter_mut().zip(&basis) {
+                *coefficient = coefficient.checked_add(term.checked_mul(difference)?)?;
+            }
+        }
+        Some(coefficients)
+    }
+}
+
+/// Writes coefficients in ascending powers as a polynomial in `x`, highest power first.
+pub fn format_polynomial(coefficients: &[Rational]) -> String {
+    let terms: Vec<(usize, Rational)> = coefficients
+        .iter()
+        .copied()
+        .enumerate()
+        .rev()
+        .filter(|(_, coefficient)| !coefficient.is_zero())
+        .collect();
+    if terms.is_empty() {
+        return "0".to_string();
+    }
+    let mut output = String::new();
+    for (index, (power, coefficient)) in terms.into_iter().enumerate() {
+        let negative = coefficient.numerator() < 0;
+        let magnitude = Rational::new(coefficient.numerator().abs(), coefficient.denominator());
+        match (index, negative) {
+            (0, true) => output.push('-'),
+            (0, false) => {}
+            (_, true) => output.push_str(" - "),
+            (_, false) => output.push_str(" + "),
+        }
+        let unit = magnitude == Rational::from(1);
+        match power {
+            0 => output += &magnitude.to_string(),
+            _ if unit => {}
+            _ => output += &format!("{} ", magnitude),
+        }
+        match power {
+            0 => {}
+            1 => output.push('x'),
+            _ => output += &format!("x^{}", power),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BigInt;
+
+    #[test]
+    fn test_difference_table() {
+        let table = DifferenceTable::new(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(table.diagonal, vec![10, 3, 0, 2, 0, 0]);
+        assert_eq!(table.degree(), 3);
+        assert!(table.converges());
+        assert!(!DifferenceTable::new(&[1, 2, 4]).unwrap().converges());
+        assert_eq!(table.forward(1), Some(68));
+        assert_eq!(table.backward(1), Some(5));
+        assert_eq!(table.forward(3), Some(146));
+        assert_eq!(table.backward(2), Some(-4));
+        assert_eq!(table.value_at(2), Some(16));
+    }
+
+    #[test]
+    fn test_matches_repeated_differences() {
+        let history = [3, -1, 7, 2, 9, 14, -20];
+        let table = DifferenceTable::new(&history).unwrap();
+        // Extend the history one step at a time by summing the last differences.
+        let mut extended = history.to_vec();
+        for steps in 1..=5 {
+            let mut level = extended.clone();
+            let mut next = 0;
+            while !level.is_empty() {
+                next += level[level.len() - 1];
+                level = level.windows(2).map(|pair| pair[1] - pair[0]).collect();
+            }
+            extended.push(next);
+            assert_eq!(table.forward(steps), Some(next));
+        }
+    }
+
+    #[test]
+    fn test_coefficients() {
+        // 1, 3, 6, 10, ... is (x + 1)(x + 2) / 2.
+        let table = DifferenceTable::new(&[1, 3, 6, 10, 15, 21]).unwrap();
+        let coefficients = table.coefficients().unwrap();
+        assert_eq!(
+            coefficients,
+            vec![Rational::from(1), Rational::new(3, 2), Rational::new(1, 2)]
+        );
+        assert_eq!(format_polynomial(&coefficients), "1/2 x^2 + 3/2 x + 1");
+        let table = DifferenceTable::new(&[0, -3, -6]).unwrap();
+        assert_eq!(format_polynomial(&table.coefficients().unwrap()), "-3 x");
+        assert_eq!(format_polynomial(&[Rational::ZERO]), "0");
+    }
+
+    #[test]
+    fn test_overflow() {
+        let history = [isize::MAX - 2, isize::MAX - 1, isize::MAX];
+        let table = DifferenceTable::new(&history).unwrap();
+        assert_eq!(table.forward(0), Some(isize::MAX));
+        assert_eq!(table.forward(1), None);
+        assert_eq!(DifferenceTable::new(&[isize::MIN, isize::MAX]), None);
+        assert_eq!(table.backward(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_bigint_does_not_overflow() {
+        let history: Vec<BigInt> = [isize::MAX - 2, isize::MAX - 1, isize::MAX]
+            .into_iter()
+            .map(BigInt::from)
+            .collect();
+        let table = DifferenceTable::new(&history).unwrap();
+        assert_eq!(
+            table.forward(1).unwrap(),
+            BigInt::from(isize::MAX as i128 + 1)
+        );
+        let wide =
+            DifferenceTable::new(&[BigInt::from(isize::MIN), BigInt::from(isize::MAX)]).unwrap();
+        assert_eq!(wide.forward(1).unwrap().to_string(), "27670116110564327422");
+    }
+}
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use crate::{difference_table, get_changes, value::Value, Error, Integer};
+
+const NEXT_COLOR: &str = "\x1b[1;32m";
+const PREVIOUS_COLOR: &str = "\x1b[1;36m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Style {
+    /// Extrapolated values in `[brackets]`.
+    Plain,
+    /// Extrapolated values in colour: green at the end, cyan at the start.
+    Ansi,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseStyleError;
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Style::Plain),
+            "ansi" => Ok(Style::Ansi),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+/// One rendered value and whether it was extrapolated, and at which end.
+enum Cell {
+    Known(String),
+    Previous(String),
+    Next(String),
+}
+
+impl Cell {
+    fn text(&self, style: Style) -> String {
+        match (self, style) {
+            (Cell::Known(text), _) => text.clone(),
+            (Cell::Previous(text) | Cell::Next(text), Style::Plain) => format!("[{}]", text),
+            (Cell::Previous(text) | Cell::Next(text), Style::Ansi) => text.clone(),
+        }
+    }
+}
+
+/// Draws the difference pyramid of the history on `line` the way the puzzle does,
+/// each row of differences shifted half a column under the row above, with the
+/// extrapolated values added at both ends of every row.
+pub fn render_pyramid(history: &[Integer], line: usize, style: Style) -> Result<String, Error> {
+    difference_table(history, line)?;
+    let mut rows = vec![history.to_vec()];
+    while rows
+        .last()
+        .is_some_and(|row| !row.iter().all(Value::is_zero))
+    {
+        let row = get_changes(rows.last().unwrap()).ok_or(Error::Overflow { line })?;
+        rows.push(row);
+    }
+
+    // Extend every row from the bottom up: the zero row extends with zeros, and
+    // each row above adds (or subtracts) the extension of the row below.
+    let mut below = (Integer::from_isize(0), Integer::from_isize(0));
+    let mut grid: Vec<Vec<Cell>> = Vec::with_capacity(rows.len());
+    for row in rows.iter().rev() {
+        let previous = Value::checked_sub(&row[0], &below.0).ok_or(Error::Overflow { line })?;
+        let next =
+            Value::checked_add(&row[row.len() - 1], &below.1).ok_or(Error::Overflow { line })?;
+        let mut cells = vec![Cell::Previous(previous.to_string())];
+        cells.extend(row.iter().map(|value| Cell::Known(value.to_string())));
+        cells.push(Cell::Next(next.to_string()));
+        grid.push(cells);
+        below = (previous, next);
+    }
+    grid.reverse();
+
+    let width = grid
+        .iter()
+        .flatten()
+        .map(|cell| cell.text(style).len())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let lines: Vec<String> = grid
+        .iter()
+        .enumerate()
+        .map(|(depth, cells)| {
+            let mut output = " ".repeat(depth * width / 2);
+            for cell in cells {
+                let text = cell.text(style);
+                output += &" ".repeat(width - text.len());
+                match (cell, style) {
+                    (Cell::Previous(_), Style::Ansi) => {
+                        output += &format!("{}{}{}", PREVIOUS_COLOR, text, RESET)
+                    }
+                    (Cell::Next(_), Style::Ansi) => {
+                        output += &format!("{}{}{}", NEXT_COLOR, text, RESET)
+                    }
+                    _ => output += &text,
+                }
+            }
+            output
+        })
+        .collect();
+    // Right-aligning leaves every line indented; drop what they all share.
+    let indent = lines
+        .iter()
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    Ok(lines
+        .iter()
+        .map(|line| format!("{}\n", &line[indent..]))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_history;
+
+    #[test]
+    fn test_render_plain() {
+        let history = parse_history("10 13 16 21 30 45", 3).unwrap();
+        let expected = "\
+[5]    10    13    16    21    30    45  [68]
+   [5]     3     3     5     9    15  [23]
+     [-2]     0     2     4     6   [8]
+         [2]     2     2     2   [2]
+            [0]     0     0   [0]
+";
+        assert_eq!(
+            render_pyramid(&history, 3, Style::Plain),
+            Ok(expected.to_string())
+        );
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let history = parse_history("0 0", 1).unwrap();
+        assert_eq!(
+            render_pyramid(&history, 1, Style::Ansi),
+            Ok("\x1b[1;36m0\x1b[0m  0  0  \x1b[1;32m0\x1b[0m\n".to_string())
+        );
+        assert_eq!(
+            render_pyramid(&parse_history("1 2 4", 2).unwrap(), 2, Style::Ansi),
+            Err(Error::Diverges { line: 2 })
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+use aoc_common::math;
+
+/// An exact fraction in lowest terms with a positive denominator.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// `numerator / denominator` reduced to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// If `denominator` is zero.
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "zero denominator");
+        let (g, _, _) = math::extended_gcd(numerator, denominator);
+        let sign = denominator.signum();
+        Self {
+            numerator: sign * numerator / g,
+            denominator: sign * denominator / g,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// The sum, or `None` if an intermediate value overflows an `i128`.
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        let (g, _, _) = math::extended_gcd(self.denominator, other.denominator);
+        let denominator = (self.denominator / g).checked_mul(other.denominator)?;
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator / g)?
+            .checked_add(other.numerator.checked_mul(self.denominator / g)?)?;
+        Some(Rational::new(numerator, denominator))
+    }
+
+    /// The product, or `None` if an intermediate value overflows an `i128`.
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        // Cancel crosswise first so the products stay as small as possible.
+        let left = Rational::new(self.numerator, other.denominator);
+        let right = Rational::new(other.numerator, self.denominator);
+        Some(Rational::new(
+            left.numerator.checked_mul(right.numerator)?,
+            left.denominator.checked_mul(right.denominator)?,
+        ))
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational::new(value, 1)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+        assert_eq!(half.checked_add(third), Some(Rational::new(5, 6)));
+        assert_eq!(
+            half.checked_mul(Rational::from(-4)),
+            Some(Rational::from(-2))
+        );
+        assert_eq!(Rational::new(-5, 6).to_string(), "-5/6");
+        assert_eq!(Rational::from(7).to_string(), "7");
+        assert_eq!(
+            Rational::from(i128::MAX).checked_add(Rational::from(1)),
+            None
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use std::{fmt, str::FromStr};
+
+/// The integer arithmetic the difference table needs. Every operation is checked,
+/// so a fixed-width type reports overflow instead of wrapping.
+pub trait Value: Clone + PartialEq + fmt::Debug + fmt::Display + FromStr {
+    fn from_isize(value: isize) -> Self;
+
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    /// Divides by a positive `divisor` that is known to divide exactly.
+    fn div_exact(&self, divisor: u32) -> Self;
+
+    fn to_i128(&self) -> Option<i128>;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::from_isize(0)
+    }
+}
+
+impl Value for isize {
+    fn from_isize(value: isize) -> Self {
+        value
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        isize::checked_add(*self, *other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        isize::checked_sub(*self, *other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        isize::checked_mul(*self, *other)
+    }
+
+    fn div_exact(&self, divisor: u32) -> Self {
+        self / divisor as isize
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        Some(*self as i128)
+    }
+}